@@ -0,0 +1,190 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::Array;
+use alloc::{
+  vec::Vec,
+  alloc::Allocator,
+};
+use core::iter::Sum;
+#[cfg(feature = "rayon",)]
+use rayon::iter::{IntoParallelIterator, IndexedParallelIterator, ParallelIterator,};
+
+/// A numeric type which can be converted to an `f64` to compute a mean.
+/// 
+/// Integers wider than 53 bits are rounded to the nearest representable `f64`.
+pub trait ToF64: Copy {
+  /// Converts the value to an `f64`.
+  fn to_f64(self,) -> f64;
+}
+
+/// Implements [`ToF64`](crate::ToF64) for primitive numeric types using an `as` cast.
+macro_rules! impl_to_f64 {
+  ($($ty:ty,)*) => {
+    $(impl ToF64 for $ty {
+      #[inline]
+      fn to_f64(self,) -> f64 { self as f64 }
+    })*
+  };
+}
+
+impl_to_f64! { u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64, }
+
+/// Returns the mean of `rank` or `None` if it is empty.
+/// 
+/// # Params
+/// 
+/// rank --- The elements to average.  
+fn mean<T,>(rank: &[T],) -> Option<f64>
+  where T: ToF64, {
+  if rank.is_empty() { return None }
+
+  Some(rank.iter().map(|&x,| x.to_f64(),).sum::<f64>() / rank.len() as f64)
+}
+
+/// Reduces `rank` with `f` or returns `None` if it is empty.
+/// 
+/// # Params
+/// 
+/// rank --- The elements to reduce.  
+/// f --- Combines the accumulated value with the next element.  
+fn reduce<T,>(rank: &[T], f: impl FnMut(T, &T,) -> T,) -> Option<T>
+  where T: Clone, {
+  let (first, rest,) = rank.split_first()?;
+
+  Some(rest.iter().fold(first.clone(), f,))
+}
+
+impl<T, A,> Array<T, A,>
+  where A: Allocator, {
+  /// Folds the elements of each rank, starting each rank from a clone of `init`.
+  /// 
+  /// ```rust
+  /// use jagged_array::*;
+  /// 
+  /// let array = array![[1, 2, 3], [], [4]];
+  /// assert_eq!(array.fold_ranks(1, |acc, x| acc * x), [6, 1, 4]);
+  /// ```
+  /// 
+  /// # Params
+  /// 
+  /// init --- The initial value of each rank.  
+  /// f --- Combines the accumulated value with the next element.  
+  pub fn fold_ranks<B,>(&self, init: B, mut f: impl FnMut(B, &T,) -> B,) -> Vec<B>
+    where B: Clone, {
+    self.iter().map(|rank,| rank.iter().fold(init.clone(), &mut f,),).collect()
+  }
+  /// Reduces the elements of each rank with `f`, yielding `None` for empty ranks.
+  /// 
+  /// # Params
+  /// 
+  /// f --- Combines the accumulated value with the next element.  
+  pub fn reduce_ranks(&self, mut f: impl FnMut(T, &T,) -> T,) -> Vec<Option<T>>
+    where T: Clone, {
+    self.iter().map(|rank,| reduce(rank, &mut f,),).collect()
+  }
+  /// Sums the elements of each rank.
+  pub fn sum_ranks(&self,) -> Vec<T>
+    where T: for<'a> Sum<&'a T>, {
+    self.iter().map(|rank,| rank.iter().sum(),).collect()
+  }
+  /// Returns the smallest element of each rank, yielding `None` for empty ranks.
+  pub fn min_ranks(&self,) -> Vec<Option<&T>>
+    where T: Ord, {
+    self.iter().map(|rank,| rank.iter().min(),).collect()
+  }
+  /// Returns the largest element of each rank, yielding `None` for empty ranks.
+  pub fn max_ranks(&self,) -> Vec<Option<&T>>
+    where T: Ord, {
+    self.iter().map(|rank,| rank.iter().max(),).collect()
+  }
+  /// Returns the mean of each rank, yielding `None` for empty ranks.
+  pub fn mean_ranks(&self,) -> Vec<Option<f64>>
+    where T: ToF64, {
+    self.iter().map(mean,).collect()
+  }
+}
+
+#[cfg(feature = "rayon",)]
+impl<T, A,> Array<T, A,>
+  where T: Sync,
+    A: Allocator, {
+  /// Returns a parallel iterator over the ranks, slicing each one out of the contiguous
+  /// elements using its start offset.
+  fn par_ranks(&self,) -> impl IndexedParallelIterator<Item = &[T]> + '_ {
+    let elements = self.elements.as_slice();
+
+    crate::array::rank_starts(&self.dimensions,).into_par_iter()
+    .zip(self.dimensions.as_slice(),)
+    .map(move |(start, &files,),| &elements[start..start + files],)
+  }
+  /// Folds the elements of each rank in parallel, starting each rank from a clone of
+  /// `init`.
+  /// 
+  /// # Params
+  /// 
+  /// init --- The initial value of each rank.  
+  /// f --- Combines the accumulated value with the next element.  
+  pub fn par_fold_ranks<B,>(&self, init: B, f: impl Fn(B, &T,) -> B + Sync,) -> Vec<B>
+    where B: Clone + Send + Sync, {
+    self.par_ranks().map(|rank,| rank.iter().fold(init.clone(), &f,),).collect()
+  }
+  /// Reduces the elements of each rank with `f` in parallel, yielding `None` for empty
+  /// ranks.
+  /// 
+  /// # Params
+  /// 
+  /// f --- Combines the accumulated value with the next element.  
+  pub fn par_reduce_ranks(&self, f: impl Fn(T, &T,) -> T + Sync,) -> Vec<Option<T>>
+    where T: Clone + Send, {
+    self.par_ranks().map(|rank,| reduce(rank, &f,),).collect()
+  }
+  /// Sums the elements of each rank in parallel.
+  pub fn par_sum_ranks(&self,) -> Vec<T>
+    where T: Send + for<'a> Sum<&'a T>, {
+    self.par_ranks().map(|rank,| rank.iter().sum(),).collect()
+  }
+  /// Returns the smallest element of each rank in parallel, yielding `None` for empty
+  /// ranks.
+  pub fn par_min_ranks(&self,) -> Vec<Option<&T>>
+    where T: Ord, {
+    self.par_ranks().map(|rank,| rank.iter().min(),).collect()
+  }
+  /// Returns the largest element of each rank in parallel, yielding `None` for empty
+  /// ranks.
+  pub fn par_max_ranks(&self,) -> Vec<Option<&T>>
+    where T: Ord, {
+    self.par_ranks().map(|rank,| rank.iter().max(),).collect()
+  }
+  /// Returns the mean of each rank in parallel, yielding `None` for empty ranks.
+  pub fn par_mean_ranks(&self,) -> Vec<Option<f64>>
+    where T: ToF64, {
+    self.par_ranks().map(mean,).collect()
+  }
+}
+
+#[cfg(test,)]
+mod tests {
+  #[test]
+  fn test_aggregate_ranks() {
+    let array = crate::array![[3, 1, 2], [], [5]];
+    assert_eq!(array.fold_ranks(0, |acc, x,| acc * 10 + x,), [312, 0, 5]);
+    assert_eq!(array.reduce_ranks(|acc, x,| acc - x,), [Some(0), None, Some(5)]);
+    assert_eq!(array.sum_ranks(), [6, 0, 5]);
+    assert_eq!(array.min_ranks(), [Some(&1), None, Some(&5)]);
+    assert_eq!(array.max_ranks(), [Some(&3), None, Some(&5)]);
+    assert_eq!(array.mean_ranks(), [Some(2.0), None, Some(5.0)]);
+    assert_eq!(crate::array![[1u64, 4], [u64::MAX]].mean_ranks(), [Some(2.5), Some(u64::MAX as f64)]);
+    assert_eq!(crate::array![[-1isize, -4]].mean_ranks(), [Some(-2.5)]);
+
+    #[cfg(feature = "rayon",)]
+    {
+      assert_eq!(array.par_fold_ranks(0, |acc, x,| acc * 10 + x,), array.fold_ranks(0, |acc, x,| acc * 10 + x,));
+      assert_eq!(array.par_reduce_ranks(|acc, x,| acc - x,), array.reduce_ranks(|acc, x,| acc - x,));
+      assert_eq!(array.par_sum_ranks(), array.sum_ranks());
+      assert_eq!(array.par_min_ranks(), array.min_ranks());
+      assert_eq!(array.par_max_ranks(), array.max_ranks());
+      assert_eq!(array.par_mean_ranks(), array.mean_ranks());
+    }
+  }
+}
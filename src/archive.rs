@@ -0,0 +1,295 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::{Array, Jagged, Ranks,};
+use alloc::{
+  vec::Vec,
+  alloc::Allocator,
+};
+use core::{
+  fmt,
+  ops::Index,
+  borrow::Borrow,
+};
+use rkyv::{
+  Archive, Archived, Deserialize, Fallible, Infallible, Serialize,
+  out_field,
+  ser::{Serializer, ScratchSpace,},
+  vec::{ArchivedVec, VecResolver,},
+};
+
+/// Converts an archived offset into a `usize`.
+/// 
+/// # Params
+/// 
+/// offset --- The archived offset.  
+#[inline]
+fn offset(offset: &Archived<usize>,) -> usize {
+  match Deserialize::<usize, _>::deserialize(offset, &mut Infallible,) {
+    Ok(offset) => offset,
+    Err(never) => match never {},
+  }
+}
+
+/// An archived [`Array`](crate::Array) which can be read directly from the archived bytes.
+/// 
+/// ```rust
+/// use jagged_array::*;
+/// 
+/// let array = array![[2, 1]; 1, 2, 3];
+/// let bytes = rkyv::to_bytes::<_, 256>(&array).unwrap();
+/// 
+/// let archived = rkyv::check_archived_root::<Array<i32>>(&bytes).unwrap();
+/// assert_eq!(archived[1], [3]);
+/// ```
+#[repr(C,)]
+pub struct ArchivedArray<T,> {
+  /// The end offset of each rank within `elements`.
+  offsets: ArchivedVec<Archived<usize>>,
+  /// The elements of the `ArchivedArray`.
+  elements: ArchivedVec<T>,
+}
+
+impl<T,> ArchivedArray<T,> {
+  /// Returns the number of elements in the `ArchivedArray`.
+  #[inline]
+  pub fn len(&self,) -> usize { self.elements.len() }
+  /// Returns `true` if the `ArchivedArray` contains no elements.
+  #[inline]
+  pub fn is_empty(&self,) -> bool { self.elements.is_empty() }
+  /// Returns the number of ranks (rows) making up the `ArchivedArray`.
+  #[inline]
+  pub fn ranks(&self,) -> usize { self.offsets.len() }
+  /// Returns the number of files (columns) within the given `rank`.
+  /// 
+  /// Defaults to `0` for nonexistant ranks.
+  /// 
+  /// # Params
+  /// 
+  /// rank --- The rank to get the files for.  
+  pub fn files(&self, rank: usize,) -> usize { self.rank(rank,).map_or(0, <[_]>::len,) }
+  /// A panic free way to index a rank of an `ArchivedArray`.
+  /// 
+  /// # Params
+  /// 
+  /// rank --- The rank to reference.  
+  pub fn rank(&self, rank: usize,) -> Option<&[T]> {
+    let end = offset(self.offsets.get(rank,)?,);
+    let start = rank.checked_sub(1,).map_or(0, |rank,| offset(&self.offsets[rank],),);
+
+    Some(&self.elements[start..end])
+  }
+  /// A panic free way to index an `ArchivedArray`.
+  /// 
+  /// # Params
+  /// 
+  /// index --- The rank and file of the element.  
+  pub fn get(&self, index: impl Borrow<[usize; 2]>,) -> Option<&T> {
+    let index = index.borrow();
+
+    self.rank(index[0],)?.get(index[1],)
+  }
+  /// Returns an iterator over all of the ranks (rows) of this `ArchivedArray`.
+  #[inline]
+  pub fn iter(&self,) -> Ranks<'_, Self,> { Ranks::new(self,) }
+}
+
+/// The resolver for an archived [`Array`](crate::Array).
+pub struct ArrayResolver {
+  /// The resolver for the offsets.
+  offsets: VecResolver,
+  /// The resolver for the elements.
+  elements: VecResolver,
+}
+
+impl<T, A,> Archive for Array<T, A,>
+  where T: Archive,
+    A: Allocator, {
+  type Archived = ArchivedArray<T::Archived>;
+  type Resolver = ArrayResolver;
+
+  #[inline]
+  unsafe fn resolve(&self, pos: usize, resolver: Self::Resolver, out: *mut Self::Archived,) {
+    let (fp, fo,) = out_field!(out.offsets);
+    ArchivedVec::resolve_from_len(self.ranks(), pos + fp, resolver.offsets, fo,);
+    let (fp, fo,) = out_field!(out.elements);
+    ArchivedVec::resolve_from_len(self.len(), pos + fp, resolver.elements, fo,);
+  }
+}
+
+impl<T, A, S,> Serialize<S> for Array<T, A,>
+  where T: Serialize<S>,
+    A: Allocator,
+    S: ScratchSpace + Serializer + ?Sized, {
+  fn serialize(&self, serializer: &mut S,) -> Result<Self::Resolver, S::Error> {
+    let mut end = 0;
+    let offsets = self.dimensions.iter().map(|files,| { end += files; end },);
+    let offsets = ArchivedVec::serialize_from_iter::<usize, usize, _, _>(offsets, serializer,)?;
+    let elements = ArchivedVec::serialize_from_iter::<T, &T, _, _>(self.elements.iter(), serializer,)?;
+
+    Ok(ArrayResolver { offsets, elements, })
+  }
+}
+
+impl<T, D,> Deserialize<Array<T,>, D> for ArchivedArray<T::Archived>
+  where T: Archive,
+    T::Archived: Deserialize<T, D>,
+    D: Fallible + ?Sized, {
+  fn deserialize(&self, deserializer: &mut D,) -> Result<Array<T,>, D::Error> {
+    let mut elements = Vec::with_capacity(self.len(),);
+    for element in self.elements.iter() { elements.push(element.deserialize(deserializer,)?,) }
+
+    Ok(Array { dimensions: self.iter().map(<[_]>::len,).collect(), elements, })
+  }
+}
+
+impl<T, U,> PartialEq<Array<U,>> for ArchivedArray<T,>
+  where T: PartialEq<U>, {
+  fn eq(&self, rhs: &Array<U,>,) -> bool {
+    self.ranks() == rhs.ranks() && self.iter().zip(rhs.iter(),).all(|(lhs, rhs,),| lhs == rhs,)
+  }
+}
+
+impl<T,> Index<usize> for ArchivedArray<T,> {
+  type Output = [T];
+
+  fn index(&self, index: usize,) -> &Self::Output {
+    self.rank(index,).expect("`index` is not within the `ArchivedArray` bounds")
+  }
+}
+
+impl<T,> Index<[usize; 2]> for ArchivedArray<T,> {
+  type Output = T;
+
+  fn index(&self, index: [usize; 2],) -> &Self::Output {
+    self.get(index,).expect("`index` is not within the `ArchivedArray` bounds")
+  }
+}
+
+impl<T,> Jagged for ArchivedArray<T,> {
+  type Element = T;
+  type RankIter<'b,> = Ranks<'b, Self,>
+    where Self: 'b;
+
+  #[inline]
+  fn ranks(&self,) -> usize { ArchivedArray::ranks(self,) }
+  #[inline]
+  fn rank(&self, rank: usize,) -> Option<&[T]> { ArchivedArray::rank(self, rank,) }
+  #[inline]
+  fn len(&self,) -> usize { ArchivedArray::len(self,) }
+  #[inline]
+  fn rank_iter(&self,) -> Ranks<'_, Self,> { Ranks::new(self,) }
+}
+
+impl<'a, T,> IntoIterator for &'a ArchivedArray<T,> {
+  type IntoIter = Ranks<'a, ArchivedArray<T,>,>;
+  type Item = &'a [T];
+
+  #[inline]
+  fn into_iter(self,) -> Self::IntoIter { self.iter() }
+}
+
+impl<T,> fmt::Debug for ArchivedArray<T,>
+  where T: fmt::Debug, {
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result {
+    fmt.debug_list()
+    .entries(self.iter(),)
+    .finish()
+  }
+}
+
+/// The error returned when validating an [`ArchivedArray`](crate::ArchivedArray) fails.
+#[derive(Debug,)]
+pub enum ArchivedArrayError<O, E,> {
+  /// The offsets failed to validate.
+  Offsets(O,),
+  /// The elements failed to validate.
+  Elements(E,),
+  /// The offsets are not monotonic or do not end at the element count.
+  BadOffsets,
+}
+
+impl<O, E,> fmt::Display for ArchivedArrayError<O, E,>
+  where O: fmt::Display,
+    E: fmt::Display, {
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result {
+    match self {
+      ArchivedArrayError::Offsets(error,) => write!(fmt, "invalid offsets: {}", error,),
+      ArchivedArrayError::Elements(error,) => write!(fmt, "invalid elements: {}", error,),
+      ArchivedArrayError::BadOffsets => write!(fmt, "offsets are out of bounds",),
+    }
+  }
+}
+
+#[cfg(feature = "std",)]
+impl<O, E,> std::error::Error for ArchivedArrayError<O, E,>
+  where O: std::error::Error + 'static,
+    E: std::error::Error + 'static, {
+  fn source(&self,) -> Option<&(dyn std::error::Error + 'static)> {
+    match self {
+      ArchivedArrayError::Offsets(error,) => Some(error,),
+      ArchivedArrayError::Elements(error,) => Some(error,),
+      ArchivedArrayError::BadOffsets => None,
+    }
+  }
+}
+
+impl<T, C,> rkyv::CheckBytes<C> for ArchivedArray<T,>
+  where T: rkyv::CheckBytes<C>,
+    C: rkyv::validation::ArchiveContext + ?Sized,
+    C::Error: rkyv::bytecheck::Error, {
+  type Error = ArchivedArrayError<
+    <ArchivedVec<Archived<usize>> as rkyv::CheckBytes<C>>::Error,
+    <ArchivedVec<T> as rkyv::CheckBytes<C>>::Error,
+  >;
+
+  unsafe fn check_bytes<'a>(value: *const Self, context: &mut C,) -> Result<&'a Self, Self::Error> {
+    ArchivedVec::check_bytes(core::ptr::addr_of!((*value).offsets), context,).map_err(ArchivedArrayError::Offsets,)?;
+    ArchivedVec::check_bytes(core::ptr::addr_of!((*value).elements), context,).map_err(ArchivedArrayError::Elements,)?;
+
+    let value = &*value;
+    let monotonic = value.offsets.windows(2,).all(|pair,| offset(&pair[0],) <= offset(&pair[1],),);
+    let end = value.offsets.last().map_or(0, offset,);
+    if !monotonic || end != value.len() { return Err(ArchivedArrayError::BadOffsets) }
+
+    Ok(value)
+  }
+}
+
+#[cfg(test,)]
+mod tests {
+  use super::*;
+  use rkyv::validation::CheckArchiveError;
+
+  #[test]
+  fn test_archived_array() {
+    let array = crate::array![[2, 0, 3]; 1, 2, 3, 4, 5];
+    let bytes = rkyv::to_bytes::<_, 256>(&array).expect("failed to serialize the array");
+
+    let archived = rkyv::check_archived_root::<Array<i32>>(&bytes).expect("failed to validate the array");
+    assert_eq!(archived.len(), 5);
+    assert_eq!(archived.ranks(), 3);
+    assert_eq!(archived.files(1), 0);
+    assert_eq!(archived.get([2, 1]), Some(&4));
+    assert_eq!(archived.rank(0), Some(&[1, 2][..]));
+    assert_eq!(*archived, array);
+
+    let deserialized: Array<i32> = archived.deserialize(&mut Infallible).expect("failed to deserialize the array");
+    assert_eq!(deserialized, array);
+
+    //Overwrites the rank offset at `rank` and revalidates the archive.
+    let start = archived.offsets.as_ptr() as usize - bytes.as_ptr() as usize;
+    let corrupt = |rank: usize, end: u32,| {
+      let mut bytes = bytes.to_vec();
+      let at = start + rank * core::mem::size_of::<Archived<usize>>();
+      bytes[at..at + 4].copy_from_slice(&end.to_ne_bytes(),);
+
+      matches!(
+        rkyv::check_archived_root::<Array<i32>>(&bytes),
+        Err(CheckArchiveError::CheckBytesError(ArchivedArrayError::BadOffsets)),
+      )
+    };
+    assert!(corrupt(1, 1,), "non-monotonic offsets were accepted");
+    assert!(corrupt(2, 9,), "out of bounds offsets were accepted");
+  }
+}
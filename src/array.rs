@@ -9,7 +9,7 @@ use alloc::{
   },
 };
 use core::{
-  fmt,
+  fmt, ptr,
   ops::{Index, IndexMut,},
   borrow::Borrow,
   iter::{Extend, FromIterator,},
@@ -24,6 +24,20 @@ pub fn from_parts<T,>(dimensions: Vec<usize>, elements: Vec<T>,) -> Array<T,> {
   Array { dimensions, elements, }
 }
 
+#[doc(hidden)]
+#[inline]
+pub fn from_parts_in<T, A, const RANKS: usize, const ELEMS: usize,>(dimensions: [usize; RANKS], elements: [T; ELEMS], alloc: A,) -> Array<T, A,>
+  where A: Allocator + Clone, {
+  let expected = dimensions.iter().copied().sum::<usize>();
+  assert!(expected == ELEMS, "wrong number of elements; expected `{}`, found `{}`", expected, ELEMS,);
+
+  let mut array = Array::with_capacity_in(ELEMS, alloc,);
+  array.dimensions.extend(IntoIterator::into_iter(dimensions,),);
+  array.elements.extend(IntoIterator::into_iter(elements,),);
+
+  array
+}
+
 #[doc(hidden)]
 #[inline]
 pub fn from_elem<T,>(element: T, dimensions: Vec<usize>,) -> Array<T,>
@@ -43,6 +57,17 @@ pub struct Array<T, A: Allocator = Global,> {
   pub(crate) elements: Vec<T, A>,
 }
 
+/// Returns the index of the first element of each rank.
+/// 
+/// # Params
+/// 
+/// dimensions --- The rank dimensions.  
+pub(crate) fn rank_starts(dimensions: &[usize],) -> Vec<usize> {
+  let mut start = 0;
+
+  dimensions.iter().map(|files,| { let rank = start; start += files; rank },).collect()
+}
+
 impl<T,> Array<T, Global,> {
   /// Creates a new empty `Array` with no dimensions or elements.
   #[inline]
@@ -140,6 +165,39 @@ impl<T, A,> Array<T, A,>
 
     Some(unsafe { self.get_element_index_unchecked(index,) })
   }
+  /// Reorders the ranks so that rank `order[i]` becomes rank `i`.
+  /// 
+  /// # Complexity
+  /// 
+  /// O(N) where `N is Array.len`, using one temporary buffer of `N` elements.
+  /// 
+  /// # Safety
+  /// 
+  /// `order` must be a permutation of `[0, ranks)`.
+  /// 
+  /// # Params
+  /// 
+  /// order --- The rank to move into each position.  
+  pub(crate) unsafe fn reorder_ranks(&mut self, order: &[usize],) {
+    debug_assert!(order.len() == self.ranks(), "`order` is not a permutation of the ranks",);
+
+    let starts = rank_starts(&self.dimensions,);
+    let len = self.len();
+    let mut buffer = Vec::<T>::with_capacity(len,);
+    let (src, dst,) = (self.elements.as_mut_ptr(), buffer.as_mut_ptr(),);
+    let mut written = 0;
+    //Safe because `order` is a permutation so every element is moved exactly once and the
+    //elements are moved back before `buffer`, which never owns them, is dropped.
+    for &rank in order {
+      let files = self.dimensions[rank];
+      ptr::copy_nonoverlapping(src.add(starts[rank],), dst.add(written,), files,);
+      written += files;
+    }
+    ptr::copy_nonoverlapping(dst, src, len,);
+
+    let dimensions = order.iter().map(|&rank,| self.dimensions[rank],).collect::<Vec<_>>();
+    self.dimensions.copy_from_slice(&dimensions,);
+  }
   /// A panic free way to index an `Array`.
   /// 
   /// # Params
@@ -156,6 +214,51 @@ impl<T, A,> Array<T, A,>
   pub fn get_mut(&mut self, index: impl Borrow<[usize; 2]>,) -> Option<&mut T> {
     self.get_element_index(index.borrow(),).map(move |index,| &mut self.elements[index],)
   }
+  /// Returns every element of the `Array` as one contiguous slice, rank after rank.
+  #[inline]
+  pub fn as_flat_slice(&self,) -> &[T] { &self.elements }
+  /// Returns every element of the `Array` as one contiguous mutable slice, rank after
+  /// rank.
+  /// 
+  /// The slice cannot change the length of the `Array` so the shape is preserved.
+  #[inline]
+  pub fn as_flat_mut_slice(&mut self,) -> &mut [T] { &mut self.elements }
+  /// Converts a rank and file into an index into [`as_flat_slice`](Array::as_flat_slice).
+  /// 
+  /// Returns `None` if `index` is out of bounds.
+  /// 
+  /// # Complexity
+  /// 
+  /// O(R) where `R` is the rank of `index`. A [`BoxedArray`](crate::BoxedArray) stores
+  /// its rank offsets and converts in O(1).
+  /// 
+  /// # Params
+  /// 
+  /// index --- The rank and file of the element.  
+  #[inline]
+  pub fn flat_index(&self, index: impl Borrow<[usize; 2]>,) -> Option<usize> { self.get_element_index(index.borrow(),) }
+  /// Converts an index into [`as_flat_slice`](Array::as_flat_slice) into the rank and file
+  /// of the element.
+  /// 
+  /// Returns `None` if `flat` is out of bounds.
+  /// 
+  /// # Complexity
+  /// 
+  /// O(R) where `R is Array.ranks` to build the rank offsets, which are then binary
+  /// searched. A [`BoxedArray`](crate::BoxedArray) stores its rank offsets and converts in
+  /// O(log R).
+  /// 
+  /// # Params
+  /// 
+  /// flat --- The index of the element.  
+  pub fn coords(&self, flat: usize,) -> Option<[usize; 2]> {
+    if flat >= self.len() { return None }
+
+    let starts = rank_starts(&self.dimensions,);
+    //The last rank starting at or before `flat` is never empty.
+    let rank = starts.partition_point(|&start,| start <= flat,) - 1;
+    Some([rank, flat - starts[rank]],)
+  }
   /// A panic free way to index a rank of an `Array`.
   /// 
   /// # Params
@@ -220,6 +323,43 @@ impl<T, A,> Array<T, A,>
 
     Some(self.elements.drain(start..).collect())
   }
+  /// Resizes the `Array` to `ranks` ranks, appending empty ranks or dropping the last
+  /// ranks as needed.
+  /// 
+  /// # Complexity
+  /// 
+  /// O(R) where `R` is the change in ranks when growing, which only touches `dimensions`,
+  /// plus the cost of dropping the removed elements when shrinking.
+  /// 
+  /// # Params
+  /// 
+  /// ranks --- The new number of ranks.  
+  pub fn resize_ranks(&mut self, ranks: usize,) {
+    if ranks <= self.ranks() { self.truncate_ranks(ranks,) }
+    else { self.dimensions.resize(ranks, 0,) }
+  }
+  /// Resizes the `Array` to `ranks` ranks, appending the ranks returned by `f` or dropping
+  /// the last ranks as needed.
+  /// 
+  /// # Params
+  /// 
+  /// ranks --- The new number of ranks.  
+  /// f --- Returns the elements of each appended rank.  
+  pub fn resize_ranks_with<A2,>(&mut self, ranks: usize, mut f: impl FnMut() -> Vec<T, A2>,)
+    where A2: Allocator, {
+    if ranks <= self.ranks() { self.truncate_ranks(ranks,) }
+    else { for _ in self.ranks()..ranks { self.push(f(),) } }
+  }
+  /// Drops every rank after the first `ranks` ranks.
+  /// 
+  /// # Params
+  /// 
+  /// ranks --- The number of ranks to keep.  
+  fn truncate_ranks(&mut self, ranks: usize,) {
+    let len = self.dimensions.iter().take(ranks,).sum();
+    self.dimensions.truncate(ranks,);
+    self.elements.truncate(len,);
+  }
 }
 
 impl<T, A,> Default for Array<T, A,>
@@ -314,6 +454,50 @@ impl<T, A1, A2,> Extend<Vec<T, A2>> for Array<T, A1,>
   }
 }
 
+/// Places each element at its rank and file, appending empty ranks for any ranks which are
+/// skipped.
+/// 
+/// Elements which arrive in rank then file order, as yielded by
+/// [`IntoIter`](crate::IntoIter), are appended in O(1); any other element is inserted at
+/// its position in O(N).
+/// 
+/// ```rust
+/// use jagged_array::*;
+/// 
+/// let array = array![[1, 2], [3], [4, 5]];
+/// let array = array.into_iter().filter(|&([rank, _], _)| rank != 1).collect::<Array<_>>();
+/// 
+/// assert_eq!(array, array![[1, 2], [], [4, 5]]);
+/// ```
+/// 
+/// # Panics
+/// 
+/// Panics if the file of an element is greater than the number of files already in its
+/// rank, which would leave a gap within the rank.
+impl<T, A,> Extend<([usize; 2], T,)> for Array<T, A,>
+  where A: Allocator, {
+  fn extend<I,>(&mut self, elements: I,)
+    where I: IntoIterator<Item = ([usize; 2], T,)>, {
+    let elements = elements.into_iter();
+    self.elements.reserve(elements.size_hint().0,);
+
+    for ([rank, file], x,) in elements {
+      if rank >= self.ranks() { self.dimensions.resize(rank + 1, 0,) }
+
+      let files = self.dimensions[rank];
+      assert!(file <= files, "file `{}` skips past the end of rank `{}`", file, rank,);
+
+      self.dimensions[rank] += 1;
+      if rank + 1 == self.ranks() && file == files { self.elements.push(x,) }
+      else {
+        //Safe because `rank` exists and `file` is within the new bounds of the rank.
+        let index = unsafe { self.get_element_index_unchecked(&[rank, file],) };
+        self.elements.insert(index, x,);
+      }
+    }
+  }
+}
+
 impl<T, A,> fmt::Debug for Array<T, A,>
   where T: fmt::Debug,
     A: Allocator, {
@@ -324,6 +508,27 @@ impl<T, A,> fmt::Debug for Array<T, A,>
   }
 }
 
+impl<T, A,> fmt::Display for Array<T, A,>
+  where T: fmt::Display,
+    A: Allocator, {
+  /// Formats the `Array` as a nested list such as `[[1], [2, 3]]` which can be read back
+  /// using [`FromStr`](core::str::FromStr).
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result {
+    write!(fmt, "[",)?;
+    for (rank, elements,) in self.iter().enumerate() {
+      if rank != 0 { write!(fmt, ", ",)? }
+
+      write!(fmt, "[",)?;
+      for (file, element,) in elements.iter().enumerate() {
+        if file != 0 { write!(fmt, ", ",)? }
+        element.fmt(fmt,)?;
+      }
+      write!(fmt, "]",)?;
+    }
+    write!(fmt, "]",)
+  }
+}
+
 #[cfg(test,)]
 mod tests {
   #[test]
@@ -350,5 +555,19 @@ mod tests {
 
     array.extend(alloc::vec![alloc::vec![6, 7], alloc::vec![8, 9, 10]]);
     assert_eq!(array, crate::array![[3, 2, 3]; 3, 4, 5, 6, 7, 8, 9, 10]);
+
+    array.insert(1, alloc::vec![]);
+    array.as_flat_mut_slice()[3] = 0;
+    assert_eq!(array.as_flat_slice(), [3, 4, 5, 0, 7, 8, 9, 10]);
+    assert_eq!(array.flat_index([2, 1]), Some(4));
+    assert_eq!(array.flat_index([1, 0]), None);
+    assert_eq!(array.coords(4), Some([2, 1]));
+    assert_eq!(array.coords(3), Some([2, 0]));
+    assert_eq!(array.coords(8), None);
+
+    let mut array = array.into_iter().filter(|&(_, x,),| x != 10,).collect::<crate::Array<_>>();
+    assert_eq!(array, crate::array![[3, 4, 5], [], [0, 7], [8, 9]]);
+    array.extend([([5, 0], 1), ([1, 0], 2), ([0, 1], 6), ([5, 1], 11)],);
+    assert_eq!(array, crate::array![[3, 6, 4, 5], [2], [0, 7], [8, 9], [], [1, 11]]);
   }
 }
@@ -0,0 +1,252 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::{Array, Jagged, Ranks,};
+use alloc::{
+  boxed::Box,
+  alloc::{Allocator, Global,},
+};
+use core::{
+  fmt,
+  ops::Index,
+  borrow::Borrow,
+};
+
+/// A frozen jagged array with no spare capacity.
+/// 
+/// Unlike an [`Array`](crate::Array) a `BoxedArray` stores the end offset of each rank
+/// rather than its length so indexing a rank is `O(1)`.
+/// 
+/// ```rust
+/// use jagged_array::*;
+/// 
+/// let boxed = array![[2, 1]; 1, 2, 3].into_boxed();
+/// assert_eq!(boxed[1], [3]);
+/// assert_eq!(boxed.into_array(), array![[2, 1]; 1, 2, 3]);
+/// ```
+#[derive(Eq, Clone,)]
+pub struct BoxedArray<T, A: Allocator = Global,> {
+  /// The end offset of each rank within `elements`.
+  offsets: Box<[usize], A>,
+  /// The elements of the `BoxedArray`.
+  elements: Box<[T], A>,
+}
+
+impl<T, A,> BoxedArray<T, A,>
+  where A: Allocator, {
+  /// Returns the number of elements in the `BoxedArray`.
+  #[inline]
+  pub fn len(&self,) -> usize { self.elements.len() }
+  /// Returns `true` if the `BoxedArray` contains no elements.
+  #[inline]
+  pub fn is_empty(&self,) -> bool { self.elements.is_empty() }
+  /// Returns the number of ranks (rows) making up the `BoxedArray`.
+  #[inline]
+  pub fn ranks(&self,) -> usize { self.offsets.len() }
+  /// Returns the index of the first element of `rank`.
+  /// 
+  /// # Params
+  /// 
+  /// rank --- The rank to get the start of.  
+  #[inline]
+  fn start_pos(&self, rank: usize,) -> usize { rank.checked_sub(1,).map_or(0, |rank,| self.offsets[rank],) }
+  /// Returns the number of files (columns) within the given `rank`.
+  /// 
+  /// Defaults to `0` for nonexistant ranks.
+  /// 
+  /// # Params
+  /// 
+  /// rank --- The rank to get the files for.  
+  pub fn files(&self, rank: usize,) -> usize { self.rank(rank,).map_or(0, <[_]>::len,) }
+  /// A panic free way to index a rank of a `BoxedArray`.
+  /// 
+  /// # Params
+  /// 
+  /// rank --- The rank to reference.  
+  pub fn rank(&self, rank: usize,) -> Option<&[T]> {
+    let end = *self.offsets.get(rank,)?;
+
+    Some(&self.elements[self.start_pos(rank,)..end])
+  }
+  /// A panic free way to index a `BoxedArray`.
+  /// 
+  /// # Params
+  /// 
+  /// index --- The rank and file of the element.  
+  pub fn get(&self, index: impl Borrow<[usize; 2]>,) -> Option<&T> {
+    let index = index.borrow();
+
+    self.rank(index[0],)?.get(index[1],)
+  }
+  /// Returns every element of the `BoxedArray` as one contiguous slice, rank after rank.
+  #[inline]
+  pub fn as_flat_slice(&self,) -> &[T] { &self.elements }
+  /// Returns every element of the `BoxedArray` as one contiguous mutable slice, rank after
+  /// rank.
+  #[inline]
+  pub fn as_flat_mut_slice(&mut self,) -> &mut [T] { &mut self.elements }
+  /// Converts a rank and file into an index into
+  /// [`as_flat_slice`](BoxedArray::as_flat_slice).
+  /// 
+  /// Returns `None` if `index` is out of bounds.
+  /// 
+  /// # Params
+  /// 
+  /// index --- The rank and file of the element.  
+  pub fn flat_index(&self, index: impl Borrow<[usize; 2]>,) -> Option<usize> {
+    let &[rank, file,] = index.borrow();
+    let end = *self.offsets.get(rank,)?;
+    let start = self.start_pos(rank,);
+
+    if file < end - start { Some(start + file,) } else { None }
+  }
+  /// Converts an index into [`as_flat_slice`](BoxedArray::as_flat_slice) into the rank
+  /// and file of the element.
+  /// 
+  /// Returns `None` if `flat` is out of bounds.
+  /// 
+  /// # Complexity
+  /// 
+  /// O(log R) where `R is BoxedArray.ranks`.
+  /// 
+  /// # Params
+  /// 
+  /// flat --- The index of the element.  
+  pub fn coords(&self, flat: usize,) -> Option<[usize; 2]> {
+    if flat >= self.len() { return None }
+
+    //The first rank ending after `flat` is never empty.
+    let rank = self.offsets.partition_point(|&end,| end <= flat,);
+    Some([rank, flat - self.start_pos(rank,)],)
+  }
+  /// Returns an iterator over all of the ranks (rows) of this `BoxedArray`.
+  #[inline]
+  pub fn iter(&self,) -> Ranks<'_, Self,> { Ranks::new(self,) }
+  /// Converts this `BoxedArray` back into an [`Array`](crate::Array).
+  pub fn into_array(self,) -> Array<T, A,> {
+    let mut dimensions = self.offsets.into_vec();
+    for rank in (1..dimensions.len()).rev() { dimensions[rank] -= dimensions[rank - 1] }
+
+    Array { dimensions, elements: self.elements.into_vec(), }
+  }
+}
+
+impl<T, A,> Array<T, A,>
+  where A: Allocator, {
+  /// Converts this `Array` into a [`BoxedArray`](crate::BoxedArray), discarding any spare
+  /// capacity.
+  pub fn into_boxed(self,) -> BoxedArray<T, A,> {
+    let mut offsets = self.dimensions;
+    for rank in 1..offsets.len() { offsets[rank] += offsets[rank - 1] }
+
+    BoxedArray {
+      offsets: offsets.into_boxed_slice(),
+      elements: self.elements.into_boxed_slice(),
+    }
+  }
+}
+
+impl<T, A,> From<Array<T, A,>> for BoxedArray<T, A,>
+  where A: Allocator, {
+  #[inline]
+  fn from(from: Array<T, A,>,) -> Self { from.into_boxed() }
+}
+
+impl<T, A,> From<BoxedArray<T, A,>> for Array<T, A,>
+  where A: Allocator, {
+  #[inline]
+  fn from(from: BoxedArray<T, A,>,) -> Self { from.into_array() }
+}
+
+impl<T, U, A,> PartialEq<BoxedArray<U, A,>> for BoxedArray<T, A,>
+  where T: PartialEq<U>,
+    A: Allocator, {
+  fn eq(&self, rhs: &BoxedArray<U, A,>,) -> bool {
+    self.offsets == rhs.offsets && *self.elements == *rhs.elements
+  }
+}
+
+impl<T, A,> Index<usize> for BoxedArray<T, A,>
+  where A: Allocator, {
+  type Output = [T];
+
+  fn index(&self, index: usize,) -> &Self::Output {
+    self.rank(index,).expect("`index` is not within the `BoxedArray` bounds")
+  }
+}
+
+impl<T, A,> Index<[usize; 2]> for BoxedArray<T, A,>
+  where A: Allocator, {
+  type Output = T;
+
+  fn index(&self, index: [usize; 2],) -> &Self::Output {
+    self.get(index,).expect("`index` is not within the `BoxedArray` bounds")
+  }
+}
+
+impl<T, A,> Jagged for BoxedArray<T, A,>
+  where A: Allocator, {
+  type Element = T;
+  type RankIter<'b,> = Ranks<'b, Self,>
+    where Self: 'b;
+
+  #[inline]
+  fn ranks(&self,) -> usize { BoxedArray::ranks(self,) }
+  #[inline]
+  fn rank(&self, rank: usize,) -> Option<&[T]> { BoxedArray::rank(self, rank,) }
+  #[inline]
+  fn len(&self,) -> usize { BoxedArray::len(self,) }
+  #[inline]
+  fn rank_iter(&self,) -> Ranks<'_, Self,> { Ranks::new(self,) }
+}
+
+impl<'a, T, A,> IntoIterator for &'a BoxedArray<T, A,>
+  where A: Allocator, {
+  type IntoIter = Ranks<'a, BoxedArray<T, A,>,>;
+  type Item = &'a [T];
+
+  #[inline]
+  fn into_iter(self,) -> Self::IntoIter { self.iter() }
+}
+
+impl<T, A,> fmt::Debug for BoxedArray<T, A,>
+  where T: fmt::Debug,
+    A: Allocator, {
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result {
+    fmt.debug_list()
+    .entries(self.iter(),)
+    .finish()
+  }
+}
+
+#[cfg(test,)]
+mod tests {
+  use alloc::vec::Vec;
+
+  #[test]
+  fn test_boxed_array() {
+    let mut array = crate::array![[2, 0, 3]; 1, 2, 3, 4, 5];
+    array.reserve(10);
+
+    let boxed = array.clone().into_boxed();
+    assert_eq!(boxed.len(), 5);
+    assert_eq!(boxed.ranks(), 3);
+    assert_eq!(boxed.files(1), 0);
+    assert_eq!(boxed.files(2), 3);
+    assert_eq!(boxed.get([2, 1]), Some(&4));
+    assert_eq!(boxed.rank(0), Some(&[1, 2][..]));
+    assert_eq!(boxed.rank(3), None);
+    assert_eq!(boxed.iter().collect::<Vec<_>>(), [&[1, 2][..], &[], &[3, 4, 5]]);
+    assert_eq!(boxed.as_flat_slice(), [1, 2, 3, 4, 5]);
+    assert_eq!(boxed.flat_index([2, 1]), Some(3));
+    assert_eq!(boxed.flat_index([1, 0]), None);
+    assert_eq!(boxed.coords(2), Some([2, 0]));
+    assert_eq!(boxed.coords(5), None);
+    assert_eq!(boxed.flat_index([4, 0]), None);
+    assert_eq!(boxed.flat_index([2, usize::MAX]), None);
+
+    let array2 = boxed.into_array();
+    assert_eq!(array2.capacity(), 5);
+    assert_eq!(array2, array);
+  }
+}
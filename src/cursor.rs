@@ -0,0 +1,292 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::Array;
+use alloc::{
+  vec::{self, Vec,},
+  alloc::{Allocator, Global,},
+};
+use core::{fmt, mem,};
+
+/// Edits the ranks of an [`Array`](crate::Array) in order, rebuilding it in a single pass.
+/// 
+/// The ranks before the cursor have been written to the rebuilt `Array`, the ranks after
+/// it are still waiting to be moved across and the current rank sits at the end of the
+/// rebuilt elements once it has been edited, so changes to it never shift the rest of the
+/// `Array`. The remaining ranks are moved across when the cursor is committed or dropped.
+/// 
+/// ```rust
+/// use jagged_array::*;
+/// 
+/// let mut array = array![[1], [2, 3], [4]];
+/// let mut cursor = array.editor();
+/// loop {
+///   cursor.push(0);
+///   if !cursor.next_rank() { break }
+/// }
+/// cursor.commit();
+/// 
+/// assert_eq!(array, array![[1, 0], [2, 3, 0], [4, 0]]);
+/// ```
+pub struct ArrayCursor<'a, T, A: Allocator = Global,> {
+  /// The rebuilt `Array`, which holds every rank before the cursor.
+  array: &'a mut Array<T, A,>,
+  /// The elements of the ranks after the cursor.
+  elements: vec::IntoIter<T, A>,
+  /// The number of files in each rank after the cursor.
+  dimensions: vec::IntoIter<usize, A>,
+  /// The start of the current rank in the rebuilt elements, when `open`.
+  start: usize,
+  /// Whether the current rank has been moved to the end of the rebuilt elements.
+  open: bool,
+}
+
+impl<T, A,> ArrayCursor<'_, T, A,>
+  where A: Allocator, {
+  /// Moves the current rank to the end of the rebuilt elements so it can be edited,
+  /// returning its start.
+  /// 
+  /// # Panics
+  /// 
+  /// Panics if the cursor is past the last rank.
+  fn open(&mut self,) -> usize {
+    if !self.open {
+      let files = self.dimensions.next().expect("the cursor is past the last rank",);
+      self.start = self.array.elements.len();
+      self.array.elements.extend(self.elements.by_ref().take(files,),);
+      self.open = true;
+    }
+
+    self.start
+  }
+  /// Returns the index of the current rank.
+  #[inline]
+  pub fn rank(&self,) -> usize { self.array.dimensions.len() }
+  /// Returns the current rank or `None` if the cursor is past the last rank.
+  pub fn current(&self,) -> Option<&[T]> {
+    if self.open { return Some(&self.array.elements[self.start..]) }
+
+    let &files = self.dimensions.as_slice().first()?;
+    Some(&self.elements.as_slice()[..files])
+  }
+  /// Returns the current rank mutably or `None` if the cursor is past the last rank.
+  pub fn current_mut(&mut self,) -> Option<&mut [T]> {
+    if self.open { return Some(&mut self.array.elements[self.start..]) }
+
+    let &files = self.dimensions.as_slice().first()?;
+    Some(&mut self.elements.as_mut_slice()[..files])
+  }
+  /// Appends an element to the current rank.
+  /// 
+  /// # Complexity
+  /// 
+  /// Amortised O(1) once the current rank has been moved to the end of the rebuilt
+  /// elements, which is O(F) where `F` is the number of files in the rank.
+  /// 
+  /// # Panics
+  /// 
+  /// Panics if the cursor is past the last rank.
+  /// 
+  /// # Params
+  /// 
+  /// value --- The element to append.  
+  pub fn push(&mut self, value: T,) {
+    self.open();
+    self.array.elements.push(value,);
+  }
+  /// Inserts an element into the current rank.
+  /// 
+  /// # Complexity
+  /// 
+  /// O(F) where `F` is the number of files in the current rank.
+  /// 
+  /// # Panics
+  /// 
+  /// Panics if the cursor is past the last rank or `file` is outside the range
+  /// `[0, len]`.
+  /// 
+  /// # Params
+  /// 
+  /// file --- The index within the rank to insert the value.  
+  /// value --- The value to insert.  
+  pub fn insert(&mut self, file: usize, value: T,) {
+    let start = self.open();
+    assert!(start + file <= self.array.elements.len(), "`file` was greater than `len`",);
+
+    self.array.elements.insert(start + file, value,);
+  }
+  /// Removes and returns an element from the current rank.
+  /// 
+  /// # Complexity
+  /// 
+  /// O(F) where `F` is the number of files in the current rank.
+  /// 
+  /// # Panics
+  /// 
+  /// Panics if the cursor is past the last rank or `file` is outside the range
+  /// `[0, len)`.
+  /// 
+  /// # Params
+  /// 
+  /// file --- The index of the element to remove.  
+  pub fn remove(&mut self, file: usize,) -> T {
+    let start = self.open();
+    assert!(start + file < self.array.elements.len(), "`file` was greater than `len`",);
+
+    self.array.elements.remove(start + file,)
+  }
+  /// Replaces an element of the current rank, returning the old element.
+  /// 
+  /// # Panics
+  /// 
+  /// Panics if the cursor is past the last rank or `file` is outside the range
+  /// `[0, len)`.
+  /// 
+  /// # Params
+  /// 
+  /// file --- The index of the element to replace.  
+  /// value --- The new element.  
+  pub fn replace(&mut self, file: usize, value: T,) -> T {
+    let rank = self.current_mut().expect("the cursor is past the last rank",);
+
+    mem::replace(&mut rank[file], value,)
+  }
+  /// Inserts a new rank before the current rank; the cursor stays on the current rank.
+  /// 
+  /// # Complexity
+  /// 
+  /// O(M) where `M` is the number of new elements, plus O(F) where `F` is the number of
+  /// files in the current rank if it has already been edited.
+  /// 
+  /// # Params
+  /// 
+  /// elements --- The elements of the new rank.  
+  pub fn insert_rank(&mut self, elements: Vec<T, impl Allocator>,) {
+    let files = elements.len();
+    self.array.elements.extend(elements,);
+    if self.open {
+      self.array.elements[self.start..].rotate_right(files,);
+      self.start += files;
+    }
+
+    self.array.dimensions.push(files,);
+  }
+  /// Removes the current rank and returns its elements, moving the cursor to the next rank.
+  /// 
+  /// Returns `None` if the cursor is past the last rank.
+  /// 
+  /// # Complexity
+  /// 
+  /// O(F) where `F` is the number of files in the current rank.
+  pub fn remove_rank(&mut self,) -> Option<Vec<T>> {
+    if mem::take(&mut self.open,) { return Some(self.array.elements.drain(self.start..).collect()) }
+
+    let files = self.dimensions.next()?;
+    Some(self.elements.by_ref().take(files,).collect())
+  }
+  /// Moves the cursor to the next rank.
+  /// 
+  /// Returns `false` if the cursor has moved past the last rank.
+  /// 
+  /// # Complexity
+  /// 
+  /// O(F) where `F` is the number of files in the current rank.
+  pub fn next_rank(&mut self,) -> bool {
+    if mem::take(&mut self.open,) {
+      let files = self.array.elements.len() - self.start;
+      self.array.dimensions.push(files,);
+    } else if let Some(files,) = self.dimensions.next() {
+      self.array.elements.extend(self.elements.by_ref().take(files,),);
+      self.array.dimensions.push(files,);
+    } else { return false }
+
+    !self.dimensions.as_slice().is_empty()
+  }
+  /// Finishes editing, moving the remaining ranks into the `Array`.
+  /// 
+  /// This is equivalent to dropping the cursor.
+  /// 
+  /// # Complexity
+  /// 
+  /// O(N) where `N` is the number of elements after the cursor.
+  #[inline]
+  pub fn commit(self,) {}
+}
+
+impl<T, A,> Drop for ArrayCursor<'_, T, A,>
+  where A: Allocator, {
+  fn drop(&mut self,) {
+    if mem::take(&mut self.open,) {
+      let files = self.array.elements.len() - self.start;
+      self.array.dimensions.push(files,);
+    }
+
+    self.array.dimensions.extend(self.dimensions.by_ref(),);
+    self.array.elements.extend(self.elements.by_ref(),);
+  }
+}
+
+impl<T, A,> fmt::Debug for ArrayCursor<'_, T, A,>
+  where T: fmt::Debug,
+    A: Allocator, {
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result {
+    fmt.debug_struct(stringify!(ArrayCursor),)
+    .field("rank", &self.rank(),)
+    .field("current", &self.current(),)
+    .finish()
+  }
+}
+
+impl<T, A,> Array<T, A,>
+  where A: Allocator + Clone, {
+  /// Returns an [`ArrayCursor`](crate::ArrayCursor) positioned on the first rank.
+  /// 
+  /// The `Array` is rebuilt into a new allocation as the cursor moves, so any number of
+  /// edits costs O(N) in total plus the cost of each edit within its rank.
+  pub fn editor(&mut self,) -> ArrayCursor<'_, T, A,> {
+    let (len, ranks, alloc,) = (self.len(), self.ranks(), self.allocator().clone(),);
+    let elements = mem::replace(&mut self.elements, Vec::with_capacity_in(len, alloc.clone(),),);
+    let dimensions = mem::replace(&mut self.dimensions, Vec::with_capacity_in(ranks, alloc,),);
+
+    ArrayCursor {
+      array: self,
+      elements: elements.into_iter(),
+      dimensions: dimensions.into_iter(),
+      start: 0,
+      open: false,
+    }
+  }
+}
+
+#[cfg(test,)]
+mod tests {
+  #[test]
+  fn test_array_cursor() {
+    let mut array = crate::array![[1, 2], [], [3], [4, 5]];
+    let mut cursor = array.editor();
+    assert_eq!(cursor.current(), Some(&[1, 2][..]));
+    assert_eq!(cursor.replace(1, 6,), 2);
+    cursor.insert(0, 7,);
+    cursor.insert_rank(alloc::vec![8],);
+    assert_eq!(cursor.rank(), 1);
+    assert_eq!(cursor.current(), Some(&[7, 1, 6][..]));
+    assert_eq!(cursor.remove(1,), 1);
+
+    assert!(cursor.next_rank());
+    cursor.push(9,);
+    assert!(cursor.next_rank());
+    assert_eq!(cursor.remove_rank(), Some(alloc::vec![3]));
+    assert_eq!(cursor.current(), Some(&[4, 5][..]));
+    cursor.insert_rank(alloc::vec![],);
+    drop(cursor,);
+    assert_eq!(array, crate::array![[8], [7, 6], [9], [], [4, 5]]);
+
+    let mut cursor = array.editor();
+    while cursor.next_rank() {}
+    assert_eq!(cursor.current(), None);
+    cursor.insert_rank(alloc::vec![10],);
+    assert_eq!(cursor.remove_rank(), None);
+    cursor.commit();
+    assert_eq!(array, crate::array![[8], [7, 6], [9], [], [4, 5], [10]]);
+  }
+}
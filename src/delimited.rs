@@ -0,0 +1,291 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::Array;
+use alloc::{
+  string::String,
+  alloc::Allocator,
+};
+use core::{
+  fmt, mem,
+  str::FromStr,
+  convert::Infallible,
+  fmt::Write as _,
+};
+use std::io::{self, BufRead,};
+
+/// How fields of a delimited file are quoted.
+#[derive(PartialEq, Eq, Clone, Copy, Debug,)]
+pub enum QuoteRules {
+  /// Fields are never quoted; every character is read literally.
+  Unquoted,
+  /// Fields may be wrapped in the quote character.
+  /// 
+  /// A quoted field may contain the delimiter and line breaks and a doubled quote
+  /// character within a quoted field is read as a single literal quote.
+  Quoted(char,),
+}
+
+impl QuoteRules {
+  /// Returns the quote character, if any.
+  #[inline]
+  fn quote(self,) -> Option<char> {
+    match self {
+      QuoteRules::Unquoted => None,
+      QuoteRules::Quoted(quote,) => Some(quote,),
+    }
+  }
+}
+
+impl Default for QuoteRules {
+  /// Fields quoted with `"` as in RFC 4180.
+  #[inline]
+  fn default() -> Self { QuoteRules::Quoted('"',) }
+}
+
+/// The error returned when reading a delimited file fails.
+#[derive(Debug,)]
+pub enum DelimitedError<E = Infallible,> {
+  /// Reading from the underlying reader failed.
+  Io(io::Error,),
+  /// The input ended within a quoted field of `rank`.
+  UnterminatedQuote {
+    /// The rank containing the unterminated field.
+    rank: usize,
+  },
+  /// A field could not be parsed.
+  Parse {
+    /// The rank and file of the field.
+    index: [usize; 2],
+    /// The error returned by the parser.
+    error: E,
+  },
+}
+
+impl<E,> From<io::Error> for DelimitedError<E,> {
+  #[inline]
+  fn from(from: io::Error,) -> Self { DelimitedError::Io(from,) }
+}
+
+impl<E,> fmt::Display for DelimitedError<E,>
+  where E: fmt::Display, {
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result {
+    match self {
+      DelimitedError::Io(error,) => write!(fmt, "{}", error,),
+      DelimitedError::UnterminatedQuote { rank, } => write!(fmt, "unterminated quote in rank {}", rank,),
+      DelimitedError::Parse { index, error, } => write!(fmt, "failed to parse the field at {:?}: {}", index, error,),
+    }
+  }
+}
+
+impl<E,> std::error::Error for DelimitedError<E,>
+  where E: std::error::Error + 'static, {
+  fn source(&self,) -> Option<&(dyn std::error::Error + 'static)> {
+    match self {
+      DelimitedError::Io(error,) => Some(error,),
+      DelimitedError::UnterminatedQuote { .. } => None,
+      DelimitedError::Parse { error, .. } => Some(error,),
+    }
+  }
+}
+
+/// Reads a delimited file with one rank per record, converting each field with `parse`.
+/// 
+/// # Params
+/// 
+/// reader --- The reader to read the records from.  
+/// delimiter --- The character separating fields.  
+/// quote --- How fields are quoted.  
+/// parse --- Converts a field into an element.  
+fn read_delimited<T, E,>(
+  mut reader: impl BufRead,
+  delimiter: char,
+  quote: QuoteRules,
+  mut parse: impl FnMut(String,) -> Result<T, E>,
+) -> Result<Array<T,>, DelimitedError<E,>> {
+  let quote = quote.quote();
+  let mut array = Array::new();
+  let (mut line, mut field,) = (String::new(), String::new(),);
+
+  loop {
+    line.clear();
+    if reader.read_line(&mut line,)? == 0 { return Ok(array) }
+
+    let rank = array.ranks();
+    let (mut files, mut empty, mut quoted, mut field_start,) = (0, true, false, true,);
+    loop {
+      let mut chars = line.chars().peekable();
+      while let Some(c,) = chars.next() {
+        if quoted {
+          //A doubled quote is a literal quote.
+          if Some(c,) != quote || chars.next_if_eq(&c,).is_some() { field.push(c,) }
+          else { quoted = false }
+        } else if c == '\n' || (c == '\r' && chars.peek() == Some(&'\n',)) {
+          continue
+        } else if c == delimiter {
+          let element = parse(mem::take(&mut field,),)
+            .map_err(|error,| DelimitedError::Parse { index: [rank, files], error, },)?;
+          array.elements.push(element,);
+          files += 1; field_start = true; empty = false;
+          continue
+        } else if field_start && Some(c,) == quote {
+          quoted = true;
+        } else {
+          field.push(c,)
+        }
+
+        field_start = false; empty = false;
+      }
+
+      if !quoted { break }
+      //The quoted field continues onto the next line.
+      line.clear();
+      if reader.read_line(&mut line,)? == 0 { return Err(DelimitedError::UnterminatedQuote { rank, }) }
+    }
+
+    //An empty line is an empty rank.
+    if !empty {
+      let element = parse(mem::take(&mut field,),)
+        .map_err(|error,| DelimitedError::Parse { index: [rank, files], error, },)?;
+      array.elements.push(element,);
+      files += 1;
+    }
+    array.dimensions.push(files,);
+  }
+}
+
+impl Array<String,> {
+  /// Reads a delimited file (such as a CSV or TSV file) into an `Array` with one rank per
+  /// record.
+  /// 
+  /// Records may have any number of fields. Both `\n` and `\r\n` line endings are
+  /// accepted and an empty line is read as an empty rank. A quote character which does not
+  /// begin a field is read literally.
+  /// 
+  /// ```rust
+  /// use jagged_array::*;
+  /// 
+  /// let csv = "a,b\r\n\"c,d\",\"e \"\"f\"\"\"\n\ng\n";
+  /// let array = Array::from_delimited(csv.as_bytes(), ',', QuoteRules::default()).unwrap();
+  /// 
+  /// assert_eq!(array[0], ["a", "b"]);
+  /// assert_eq!(array[1], ["c,d", "e \"f\""]);
+  /// assert_eq!(array.files(2), 0);
+  /// assert_eq!(array[3], ["g"]);
+  /// ```
+  /// 
+  /// # Errors
+  /// 
+  /// Returns an error if reading fails or the input ends within a quoted field.
+  /// 
+  /// # Params
+  /// 
+  /// reader --- The reader to read the records from.  
+  /// delimiter --- The character separating fields.  
+  /// quote --- How fields are quoted.  
+  pub fn from_delimited(reader: impl BufRead, delimiter: char, quote: QuoteRules,) -> Result<Self, DelimitedError> {
+    read_delimited(reader, delimiter, quote, Ok,)
+  }
+}
+
+impl<T,> Array<T,>
+  where T: FromStr, {
+  /// Reads a delimited file into an `Array` with one rank per record, parsing each field.
+  /// 
+  /// Fields are read as in [`from_delimited`](crate::Array::from_delimited).
+  /// 
+  /// # Errors
+  /// 
+  /// Returns an error if reading fails, the input ends within a quoted field or a field
+  /// fails to parse, in which case the rank and file of the field is reported.
+  /// 
+  /// # Params
+  /// 
+  /// reader --- The reader to read the records from.  
+  /// delimiter --- The character separating fields.  
+  /// quote --- How fields are quoted.  
+  pub fn parse_delimited(reader: impl BufRead, delimiter: char, quote: QuoteRules,) -> Result<Self, DelimitedError<T::Err,>> {
+    read_delimited(reader, delimiter, quote, |field,| field.parse(),)
+  }
+}
+
+impl<T, A,> Array<T, A,>
+  where T: fmt::Display,
+    A: Allocator, {
+  /// Writes this `Array` as a delimited file with one record per rank.
+  /// 
+  /// Records are terminated with `\n`. Fields which contain the delimiter, the quote
+  /// character or a line break are quoted, as is the field of a rank containing a single
+  /// empty field so that it is not read back as an empty rank.
+  /// 
+  /// # Errors
+  /// 
+  /// Returns an error if writing fails or a field needs quoting but `quote` is
+  /// [`Unquoted`](crate::QuoteRules::Unquoted).
+  /// 
+  /// # Params
+  /// 
+  /// writer --- The writer to write the records to.  
+  /// delimiter --- The character separating fields.  
+  /// quote --- How fields are quoted.  
+  pub fn write_delimited(&self, mut writer: impl io::Write, delimiter: char, quote: QuoteRules,) -> io::Result<()> {
+    let quote = quote.quote();
+    let mut field = String::new();
+
+    for rank in self.iter() {
+      for (file, element,) in rank.iter().enumerate() {
+        if file != 0 { write!(writer, "{}", delimiter,)? }
+
+        field.clear();
+        //Writing to a `String` only fails if the `Display` implementation fails.
+        write!(field, "{}", element,).map_err(|_,| io::Error::other("formatter error",),)?;
+        let special = (field.is_empty() && rank.len() == 1)
+          || field.contains(|c,| c == delimiter || c == '\n' || c == '\r' || Some(c,) == quote,);
+        if !special { writer.write_all(field.as_bytes(),)?; continue }
+
+        let quote = quote.ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "field requires quoting",),)?;
+        write!(writer, "{}", quote,)?;
+        for (index, part,) in field.split(quote,).enumerate() {
+          if index != 0 { write!(writer, "{0}{0}", quote,)? }
+          writer.write_all(part.as_bytes(),)?;
+        }
+        write!(writer, "{}", quote,)?;
+      }
+
+      writer.write_all(b"\n",)?;
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(test,)]
+mod tests {
+  use super::*;
+  use alloc::{vec::Vec, string::ToString,};
+
+  #[test]
+  fn test_delimited() {
+    let tsv = "1\t2\n\n3\r\n\"4\"\t5\t6";
+    let array = Array::<i32>::parse_delimited(tsv.as_bytes(), '\t', QuoteRules::default(),)
+      .expect("failed to parse the array");
+    assert_eq!(array, crate::array![[2, 0, 1, 3]; 1, 2, 3, 4, 5, 6]);
+
+    let error = Array::<i32>::parse_delimited("1,2\n3,x".as_bytes(), ',', QuoteRules::Unquoted,);
+    assert!(matches!(error, Err(DelimitedError::Parse { index: [1, 1], .. })));
+    let error = Array::from_delimited("1,\"2\n3".as_bytes(), ',', QuoteRules::default(),);
+    assert!(matches!(error, Err(DelimitedError::UnterminatedQuote { rank: 0 })));
+
+    let mut array = Array::new();
+    array.push(alloc::vec!["a".to_string(), "b,c".to_string()]);
+    array.push(alloc::vec![String::new()]);
+    array.push(Vec::<String>::new());
+    array.push(alloc::vec!["d\"e\nf".to_string(), String::new()]);
+
+    let mut csv = Vec::new();
+    array.write_delimited(&mut csv, ',', QuoteRules::default(),).expect("failed to write the array");
+    assert_eq!(csv, b"a,\"b,c\"\n\"\"\n\n\"d\"\"e\nf\",\n");
+    assert_eq!(Array::from_delimited(&*csv, ',', QuoteRules::default(),).expect("failed to read the array"), array);
+    assert!(array.write_delimited(Vec::new(), ',', QuoteRules::Unquoted,).is_err());
+  }
+}
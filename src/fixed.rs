@@ -0,0 +1,432 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::{Iter, IterMut, Jagged, JaggedMut, JaggedPush, InlineVec, CapacityError,};
+use core::{
+  fmt,
+  ops::{Deref, DerefMut, Index, IndexMut,},
+  borrow::Borrow,
+};
+
+/// A fixed capacity jagged array which stores up to `RANKS` ranks and `ELEMS` elements
+/// inline and never allocates.
+/// 
+/// A `FixedArray` can be indexed either by a `rank/file` pair or simply by a `rank`.
+/// 
+/// ```rust
+/// use jagged_array::*;
+/// 
+/// let mut array = FixedArray::<_, 2, 3>::new();
+/// array.push([1, 2].iter().copied()).unwrap();
+/// array.push([3].iter().copied()).unwrap();
+/// assert_eq!(array[[1, 0]], 3);
+/// assert!(array.push([].iter().copied()).is_err());
+/// ```
+#[derive(Clone,)]
+pub struct FixedArray<T, const RANKS: usize, const ELEMS: usize,> {
+  /// The dimensions of the `FixedArray`.
+  dimensions: InlineVec<usize, RANKS>,
+  /// The elements of the `FixedArray`.
+  elements: InlineVec<T, ELEMS>,
+}
+
+impl<T, const RANKS: usize, const ELEMS: usize,> FixedArray<T, RANKS, ELEMS,> {
+  /// Creates a new empty `FixedArray` with no dimensions or elements.
+  #[inline]
+  pub fn new() -> Self {
+    Self {
+      dimensions: InlineVec::new(),
+      elements: InlineVec::new(),
+    }
+  }
+  /// Returns the number of elements in the `FixedArray`.
+  #[inline]
+  pub fn len(&self,) -> usize { self.elements.len() }
+  /// Returns `true` if the `FixedArray` contains no elements.
+  #[inline]
+  pub fn is_empty(&self,) -> bool { self.elements.is_empty() }
+  /// Returns the number of elements there is space for.
+  #[inline]
+  pub const fn capacity(&self,) -> usize { ELEMS }
+  /// Returns the number of ranks there is space for.
+  #[inline]
+  pub const fn rank_capacity(&self,) -> usize { RANKS }
+  /// Returns the number of ranks (rows) making up the `FixedArray`.
+  #[inline]
+  pub fn ranks(&self,) -> usize { self.dimensions.len() }
+  /// Returns the number of files (columns) within the given `rank`.
+  /// 
+  /// Defaults to `0` for nonexistant ranks.
+  /// 
+  /// # Params
+  /// 
+  /// rank --- The rank to get the files for.  
+  pub fn files(&self, rank: usize,) -> usize { self.dimensions.get(rank,).copied().unwrap_or(0,) }
+  /// Returns the index of the first element of `rank`.
+  /// 
+  /// # Params
+  /// 
+  /// rank --- The rank to get the start of.  
+  fn start_pos(&self, rank: usize,) -> usize { self.dimensions[..rank].iter().sum() }
+  /// A panic free way to index a rank of a `FixedArray`.
+  /// 
+  /// # Params
+  /// 
+  /// rank --- The rank to reference.  
+  pub fn rank(&self, rank: usize,) -> Option<&[T]> {
+    let files = *self.dimensions.get(rank,)?;
+    let start = self.start_pos(rank,);
+
+    Some(&self.elements[start..start + files])
+  }
+  /// A panic free way to mutably index a rank of a `FixedArray`.
+  /// 
+  /// # Params
+  /// 
+  /// rank --- The rank to reference.  
+  pub fn rank_mut(&mut self, rank: usize,) -> Option<FixedRank<'_, T, RANKS, ELEMS,>> {
+    if rank >= self.ranks() { return None }
+
+    Some(FixedRank {
+      rank,
+      start_pos: self.start_pos(rank,),
+      array: self,
+    })
+  }
+  /// A panic free way to index a `FixedArray`.
+  /// 
+  /// # Params
+  /// 
+  /// index --- The rank and file of the element.  
+  pub fn get(&self, index: impl Borrow<[usize; 2]>,) -> Option<&T> {
+    let index = index.borrow();
+
+    self.rank(index[0],)?.get(index[1],)
+  }
+  /// A panic free way to index a `FixedArray`.
+  /// 
+  /// # Params
+  /// 
+  /// index --- The rank and file of the element.  
+  pub fn get_mut(&mut self, index: impl Borrow<[usize; 2]>,) -> Option<&mut T> {
+    let index = index.borrow();
+    let files = *self.dimensions.get(index[0],)?;
+    if index[1] >= files { return None }
+
+    let start = self.start_pos(index[0],);
+    self.elements.get_mut(start + index[1],)
+  }
+  /// Inserts a rank into the `FixedArray`.
+  /// 
+  /// # Errors
+  /// 
+  /// Returns the unconsumed elements if there is not enough space for the rank.
+  /// 
+  /// # Panics
+  /// 
+  /// Panics if `rank` is outside the range `[0, ranks]`.
+  /// 
+  /// # Params
+  /// 
+  /// rank --- The index within the ranks to insert the values.  
+  /// elements --- The elements of the rank.  
+  pub fn insert<I,>(&mut self, rank: usize, elements: I,) -> Result<(), CapacityError<I::IntoIter>>
+    where I: IntoIterator<Item = T>,
+      I::IntoIter: ExactSizeIterator, {
+    assert!(rank <= self.ranks(), "`rank` was greater than `ranks`",);
+
+    let elements = elements.into_iter();
+    if self.dimensions.len() == RANKS { return Err(CapacityError(elements,)) }
+
+    let start = self.start_pos(rank,);
+    let len = self.elements.len();
+    self.elements.try_splice(start, elements,)?;
+    //Cannot fail because we just checked there is space for another rank.
+    let _ = self.dimensions.try_insert(rank, self.elements.len() - len,);
+
+    Ok(())
+  }
+  /// Removes and returns a rank from the `FixedArray`.
+  /// 
+  /// # Panics
+  /// 
+  /// Panics if `rank` is ouside the range `[0, ranks)`.
+  /// 
+  /// # Params
+  /// 
+  /// rank --- The index of the rank to remove.  
+  pub fn remove(&mut self, rank: usize,) -> InlineVec<T, ELEMS,> {
+    assert!(rank < self.ranks(), "`rank` was greter than `ranks`",);
+
+    let start = self.start_pos(rank,);
+    let end = start + self.dimensions.remove(rank,);
+    self.elements.remove_range(start, end,)
+  }
+  /// Appends a rank to the `FixedArray`.
+  /// 
+  /// # Errors
+  /// 
+  /// Returns the unconsumed elements if there is not enough space for the rank.
+  /// 
+  /// # Params
+  /// 
+  /// rank --- The elements to append.  
+  pub fn push<I,>(&mut self, rank: I,) -> Result<(), CapacityError<I::IntoIter>>
+    where I: IntoIterator<Item = T>,
+      I::IntoIter: ExactSizeIterator, {
+    self.insert(self.ranks(), rank,)
+  }
+  /// Pops a rank from the `FixedArray`.
+  pub fn pop(&mut self,) -> Option<InlineVec<T, ELEMS,>> {
+    let files = self.dimensions.pop()?;
+    let end = self.elements.len();
+
+    Some(self.elements.remove_range(end - files, end,))
+  }
+  /// Returns an iterator over all of the ranks (rows) of this `FixedArray`.
+  pub fn iter<'a,>(&'a self,) -> Iter<'a, T,> { Iter::new(&self.dimensions, &self.elements,) }
+  /// Returns a mutable iterator over all of the ranks (rows) of this `FixedArray`.
+  pub fn iter_mut<'a,>(&'a mut self,) -> IterMut<'a, T,> { IterMut::new(&self.dimensions, &mut self.elements,) }
+}
+
+impl<T, const RANKS: usize, const ELEMS: usize,> Default for FixedArray<T, RANKS, ELEMS,> {
+  #[inline]
+  fn default() -> Self { Self::new() }
+}
+
+impl<T, U, const RANKS: usize, const ELEMS: usize, const R: usize, const E: usize,> PartialEq<FixedArray<U, R, E,>> for FixedArray<T, RANKS, ELEMS,>
+  where T: PartialEq<U>, {
+  fn eq(&self, rhs: &FixedArray<U, R, E,>,) -> bool {
+    self.dimensions == rhs.dimensions && self.elements == rhs.elements
+  }
+}
+
+impl<T, const RANKS: usize, const ELEMS: usize,> Eq for FixedArray<T, RANKS, ELEMS,>
+  where T: Eq, {}
+
+impl<T, const RANKS: usize, const ELEMS: usize,> Index<usize> for FixedArray<T, RANKS, ELEMS,> {
+  type Output = [T];
+
+  fn index(&self, index: usize,) -> &Self::Output {
+    self.rank(index,).expect("`index` is not within the `FixedArray` bounds")
+  }
+}
+
+impl<T, const RANKS: usize, const ELEMS: usize,> IndexMut<usize> for FixedArray<T, RANKS, ELEMS,> {
+  fn index_mut(&mut self, index: usize,) -> &mut Self::Output {
+    self.rank_mut(index,).expect("`index` is not within the `FixedArray` bounds").into()
+  }
+}
+
+impl<T, const RANKS: usize, const ELEMS: usize,> Index<[usize; 2]> for FixedArray<T, RANKS, ELEMS,> {
+  type Output = T;
+
+  fn index(&self, index: [usize; 2],) -> &Self::Output {
+    self.get(index,).expect("`index` is not within the `FixedArray` bounds")
+  }
+}
+
+impl<T, const RANKS: usize, const ELEMS: usize,> IndexMut<[usize; 2]> for FixedArray<T, RANKS, ELEMS,> {
+  fn index_mut(&mut self, index: [usize; 2],) -> &mut Self::Output {
+    self.get_mut(index,).expect("`index` is not within the `FixedArray` bounds")
+  }
+}
+
+impl<T, const RANKS: usize, const ELEMS: usize,> Jagged for FixedArray<T, RANKS, ELEMS,> {
+  type Element = T;
+  type RankIter<'a,> = Iter<'a, T,>
+    where Self: 'a;
+
+  #[inline]
+  fn ranks(&self,) -> usize { FixedArray::ranks(self,) }
+  #[inline]
+  fn rank(&self, rank: usize,) -> Option<&[T]> { FixedArray::rank(self, rank,) }
+  #[inline]
+  fn files(&self, rank: usize,) -> usize { FixedArray::files(self, rank,) }
+  #[inline]
+  fn len(&self,) -> usize { FixedArray::len(self,) }
+  #[inline]
+  fn rank_iter(&self,) -> Iter<'_, T,> { FixedArray::iter(self,) }
+}
+
+impl<T, const RANKS: usize, const ELEMS: usize,> JaggedMut for FixedArray<T, RANKS, ELEMS,> {
+  #[inline]
+  fn rank_mut(&mut self, rank: usize,) -> Option<&mut [T]> { FixedArray::rank_mut(self, rank,).map(Into::into,) }
+}
+
+impl<T, const RANKS: usize, const ELEMS: usize,> JaggedPush for FixedArray<T, RANKS, ELEMS,> {
+  #[inline]
+  fn push<I,>(&mut self, rank: I,) -> Result<(), CapacityError<I::IntoIter>>
+    where I: IntoIterator<Item = T>,
+      I::IntoIter: ExactSizeIterator, { FixedArray::push(self, rank,) }
+}
+
+impl<'a, T, const RANKS: usize, const ELEMS: usize,> IntoIterator for &'a FixedArray<T, RANKS, ELEMS,> {
+  type IntoIter = Iter<'a, T,>;
+  type Item = &'a [T];
+
+  #[inline]
+  fn into_iter(self,) -> Self::IntoIter { self.iter() }
+}
+
+impl<'a, T, const RANKS: usize, const ELEMS: usize,> IntoIterator for &'a mut FixedArray<T, RANKS, ELEMS,> {
+  type IntoIter = IterMut<'a, T,>;
+  type Item = &'a mut [T];
+
+  #[inline]
+  fn into_iter(self,) -> Self::IntoIter { self.iter_mut() }
+}
+
+impl<T, const RANKS: usize, const ELEMS: usize,> fmt::Debug for FixedArray<T, RANKS, ELEMS,>
+  where T: fmt::Debug, {
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result {
+    fmt.debug_list()
+    .entries(self.iter(),)
+    .finish()
+  }
+}
+
+/// A mutable reference to a rank in a [`FixedArray`](crate::FixedArray).
+pub struct FixedRank<'a, T, const RANKS: usize, const ELEMS: usize,> {
+  rank: usize,
+  start_pos: usize,
+  array: &'a mut FixedArray<T, RANKS, ELEMS,>,
+}
+
+impl<T, const RANKS: usize, const ELEMS: usize,> FixedRank<'_, T, RANKS, ELEMS,> {
+  /// Returns a reference to the inner slice.
+  pub fn as_slice(&self,) -> &[T] {
+    let end = self.start_pos + self.array.files(self.rank,);
+
+    &self.array.elements[self.start_pos..end]
+  }
+  /// Returns a mutable reference to the inner slice.
+  pub fn as_mut_slice(&mut self,) -> &mut [T] {
+    let end = self.start_pos + self.array.files(self.rank,);
+
+    &mut self.array.elements[self.start_pos..end]
+  }
+  /// Inserts an element into the rank of the inner `FixedArray`.
+  /// 
+  /// # Errors
+  /// 
+  /// Returns the element if the `FixedArray` is full.
+  /// 
+  /// # Panics
+  /// 
+  /// Panics if `index` is outside the range `[0, len]`.
+  /// 
+  /// # Params
+  /// 
+  /// index --- The index within the rank to insert the value.  
+  /// value --- The value to insert into the rank.  
+  pub fn insert(&mut self, index: usize, value: T,) -> Result<(), CapacityError<T,>> {
+    let files = &mut self.array.dimensions[self.rank];
+    assert!(index <= *files, "`index` was greater than `len`",);
+
+    self.array.elements.try_insert(self.start_pos + index, value,)?;
+    *files += 1; Ok(())
+  }
+  /// Removes and returns an element from the rank of the inner `FixedArray`.
+  /// 
+  /// # Panics
+  /// 
+  /// Panics if `index` is ouside the range `[0, len)`.
+  /// 
+  /// # Params
+  /// 
+  /// index --- The index of the element to remove.  
+  pub fn remove(&mut self, index: usize,) -> T {
+    let files = &mut self.array.dimensions[self.rank];
+    assert!(index < *files, "`index` was greter than `len`",);
+
+    *files -= 1;
+    self.array.elements.remove(self.start_pos + index,)
+  }
+  /// Appends an element to the rank of the inner `FixedArray`.
+  /// 
+  /// # Errors
+  /// 
+  /// Returns the element if the `FixedArray` is full.
+  /// 
+  /// # Params
+  /// 
+  /// value --- The element to append.  
+  pub fn push(&mut self, value: T,) -> Result<(), CapacityError<T,>> {
+    let files = &mut self.array.dimensions[self.rank];
+
+    self.array.elements.try_insert(self.start_pos + *files, value,)?;
+    *files += 1; Ok(())
+  }
+  /// Pops an element from the rank of the inner `FixedArray`.
+  pub fn pop(&mut self,) -> Option<T> {
+    let files = &mut self.array.dimensions[self.rank];
+
+    *files = files.checked_sub(1,)?;
+    Some(self.array.elements.remove(self.start_pos + *files,))
+  }
+}
+
+impl<T, U, const RANKS: usize, const ELEMS: usize,> PartialEq<[U]> for FixedRank<'_, T, RANKS, ELEMS,>
+  where T: PartialEq<U>, {
+  fn eq(&self, rhs: &[U],) -> bool { self.as_slice() == rhs }
+}
+
+impl<T, const RANKS: usize, const ELEMS: usize,> Deref for FixedRank<'_, T, RANKS, ELEMS,> {
+  type Target = [T];
+
+  #[inline]
+  fn deref(&self,) -> &Self::Target { self.as_slice() }
+}
+
+impl<T, const RANKS: usize, const ELEMS: usize,> DerefMut for FixedRank<'_, T, RANKS, ELEMS,> {
+  #[inline]
+  fn deref_mut(&mut self,) -> &mut Self::Target { self.as_mut_slice() }
+}
+
+impl<'a, T, const RANKS: usize, const ELEMS: usize,> From<FixedRank<'a, T, RANKS, ELEMS,>> for &'a mut [T] {
+  #[inline]
+  fn from(from: FixedRank<'a, T, RANKS, ELEMS,>,) -> Self {
+    let end = from.start_pos + from.array.files(from.rank,);
+
+    &mut from.array.elements[from.start_pos..end]
+  }
+}
+
+impl<T, const RANKS: usize, const ELEMS: usize,> fmt::Debug for FixedRank<'_, T, RANKS, ELEMS,>
+  where T: fmt::Debug, {
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result {
+    fmt.debug_tuple(stringify!(FixedRank),).field(&&**self,).finish()
+  }
+}
+
+#[cfg(test,)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_fixed_array() {
+    let mut array = FixedArray::<_, 3, 5>::new();
+    assert!(array.push([1, 2].iter().copied()).is_ok());
+    assert!(array.push([3, 4].iter().copied()).is_ok());
+    assert!(array.insert(1, [5].iter().copied()).is_ok());
+    assert!(array.push([].iter().copied()).is_err());
+    assert_eq!(array.len(), 5);
+    assert_eq!(array.ranks(), 3);
+    assert_eq!(array.files(1), 1);
+    assert_eq!(array.get([2, 1]), Some(&4));
+    assert_eq!(array.rank(1), Some(&[5][..]));
+
+    let mut rank = array.rank_mut(0).expect("failed to get the rank");
+    assert_eq!(rank.push(6), Err(CapacityError(6)));
+    assert_eq!(rank.remove(0), 1);
+    assert_eq!(rank.push(6), Ok(()));
+    assert_eq!(rank, [2, 6][..]);
+
+    assert_eq!(array.remove(1).as_slice(), [5]);
+    assert_eq!(array.pop().as_deref(), Some(&[3, 4][..]));
+    let mut iter = array.iter();
+    assert_eq!(iter.next(), Some(&[2, 6][..]));
+    assert_eq!(iter.next(), None);
+  }
+}
@@ -0,0 +1,112 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::Array;
+use alloc::vec::Vec;
+
+impl<T,> Array<T,> {
+  /// Creates a new `Array` with `ranks` ranks by placing each element in the rank it is
+  /// paired with, keeping elements of the same rank in the order they are yielded.
+  /// 
+  /// ```rust
+  /// use jagged_array::*;
+  /// 
+  /// let array = Array::group_by_index(3, vec![(2, 'a'), (0, 'b'), (2, 'c')]);
+  /// assert_eq!(array, array![['b'], [], ['a', 'c']]);
+  /// ```
+  /// 
+  /// # Complexity
+  /// 
+  /// O(N + R) where `N` is the number of elements and `R` is `ranks`, using a counting
+  /// sort. `dimensions` and `elements` are allocated exactly once at their final size.
+  /// 
+  /// # Panics
+  /// 
+  /// Panics if any rank is outside the range `[0, ranks)`; this is checked before any
+  /// element is placed.
+  /// 
+  /// # Params
+  /// 
+  /// ranks --- The number of ranks in the `Array`.  
+  /// items --- The elements paired with their ranks.  
+  pub fn group_by_index(ranks: usize, items: impl IntoIterator<Item = (usize, T,)>,) -> Self {
+    let items = items.into_iter().collect::<Vec<_>>();
+    let mut dimensions = alloc::vec![0; ranks];
+    for &(rank, _,) in &items {
+      assert!(rank < ranks, "rank `{}` is out of bounds for `{}` ranks", rank, ranks,);
+      dimensions[rank] += 1;
+    }
+
+    let mut next = crate::array::rank_starts(&dimensions,);
+    let len = items.len();
+    let mut elements = Vec::<T>::with_capacity(len,);
+    let ptr = elements.as_mut_ptr();
+    //Safe because the counts give every element its own slot within `len` and nothing in
+    //this loop can panic, so `elements` is fully initialised before its length is set.
+    unsafe {
+      for (rank, x,) in items {
+        ptr.add(next[rank],).write(x,);
+        next[rank] += 1;
+      }
+      elements.set_len(len,);
+    }
+
+    Self { dimensions, elements, }
+  }
+  /// Creates a new `Array` from elements which are already grouped, starting a new rank
+  /// whenever the key of an element differs from the key of the previous element.
+  /// 
+  /// ```rust
+  /// use jagged_array::*;
+  /// 
+  /// let array = Array::from_sorted_groups(vec![10, 12, 21, 35, 38], |x| x / 10);
+  /// assert_eq!(array, array![[10, 12], [21], [35, 38]]);
+  /// ```
+  /// 
+  /// # Complexity
+  /// 
+  /// O(N) where `N` is the number of elements. `dimensions` and `elements` are shrunk to
+  /// their exact size.
+  /// 
+  /// # Params
+  /// 
+  /// items --- The grouped elements.  
+  /// key --- Returns the key of an element.  
+  pub fn from_sorted_groups<K,>(items: impl IntoIterator<Item = T>, mut key: impl FnMut(&T,) -> K,) -> Self
+    where K: PartialEq, {
+    let items = items.into_iter();
+    let mut array = Self::with_capacity(items.size_hint().0,);
+    let mut last = None;
+
+    for x in items {
+      let next = key(&x,);
+      if last.as_ref() != Some(&next,) { array.dimensions.push(0,) }
+      last = Some(next,);
+
+      //Cannot fail because a rank was pushed for the first element.
+      if let Some(files,) = array.dimensions.last_mut() { *files += 1 }
+      array.elements.push(x,);
+    }
+
+    array.dimensions.shrink_to_fit();
+    array.elements.shrink_to_fit();
+    array
+  }
+}
+
+#[cfg(test,)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_group_array() {
+    let array = Array::group_by_index(4, alloc::vec![(3, 'a'), (0, 'b'), (3, 'c'), (1, 'd'), (0, 'e')],);
+    assert_eq!(array, crate::array![['b', 'e'], ['d'], [], ['a', 'c']]);
+    assert_eq!(array.capacity(), 5);
+
+    let array = Array::from_sorted_groups("aabccca".chars(), |&c,| c,);
+    assert_eq!(array, crate::array![['a', 'a'], ['b'], ['c', 'c', 'c'], ['a']]);
+    assert_eq!(array.capacity(), 7);
+    assert_eq!(Array::from_sorted_groups(Vec::<i32>::new(), |&x,| x,).ranks(), 0);
+  }
+}
@@ -0,0 +1,381 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use core::{
+  fmt, ptr, slice,
+  mem::MaybeUninit,
+  ops::{Deref, DerefMut,},
+  iter::FusedIterator,
+};
+
+/// The error returned when there is not enough space remaining in a fixed capacity
+/// buffer.
+/// 
+/// Contains the value which could not be inserted.
+#[derive(PartialEq, Eq, Clone, Copy, Debug,)]
+pub struct CapacityError<T = (),>(pub T,);
+
+impl<T,> fmt::Display for CapacityError<T,> {
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result { write!(fmt, "insufficient capacity",) }
+}
+
+/// A `Vec`-like buffer which stores up to `N` elements inline.
+pub struct InlineVec<T, const N: usize,> {
+  /// The number of initialised elements.
+  len: usize,
+  /// The element storage.
+  buffer: [MaybeUninit<T>; N],
+}
+
+impl<T, const N: usize,> InlineVec<T, N,> {
+  /// Creates a new empty `InlineVec`.
+  #[inline]
+  pub fn new() -> Self {
+    Self {
+      len: 0,
+      //Safe because an array of `MaybeUninit` does not require initialisation.
+      buffer: unsafe { MaybeUninit::uninit().assume_init() },
+    }
+  }
+  /// Returns the number of elements in the `InlineVec`.
+  #[inline]
+  pub fn len(&self,) -> usize { self.len }
+  /// Returns `true` if the `InlineVec` contains no elements.
+  #[inline]
+  pub fn is_empty(&self,) -> bool { self.len == 0 }
+  /// Returns the number of elements there is space for.
+  #[inline]
+  pub const fn capacity(&self,) -> usize { N }
+  /// Returns a reference to the initialised elements.
+  #[inline]
+  pub fn as_slice(&self,) -> &[T] {
+    //Safe because `len <= N` and the first `len` slots are always initialised.
+    unsafe { core::slice::from_raw_parts(self.buffer.as_ptr() as *const T, self.len,) }
+  }
+  /// Returns a mutable reference to the initialised elements.
+  #[inline]
+  pub fn as_mut_slice(&mut self,) -> &mut [T] {
+    //Safe because `len <= N` and the first `len` slots are always initialised.
+    unsafe { core::slice::from_raw_parts_mut(self.buffer.as_mut_ptr() as *mut T, self.len,) }
+  }
+  /// Appends an element.
+  /// 
+  /// # Errors
+  /// 
+  /// Returns the element if there is no space remaining.
+  /// 
+  /// # Params
+  /// 
+  /// value --- The element to append.  
+  pub fn try_push(&mut self, value: T,) -> Result<(), CapacityError<T,>> {
+    if self.len == N { return Err(CapacityError(value,)) }
+
+    self.buffer[self.len] = MaybeUninit::new(value,);
+    self.len += 1; Ok(())
+  }
+  /// Pops the last element.
+  pub fn pop(&mut self,) -> Option<T> {
+    self.len = self.len.checked_sub(1,)?;
+
+    //Safe because the element at `len` was initialised and is no longer tracked.
+    Some(unsafe { self.buffer[self.len].as_ptr().read() })
+  }
+  /// Inserts an element at `index`.
+  /// 
+  /// # Errors
+  /// 
+  /// Returns the element if there is no space remaining.
+  /// 
+  /// # Panics
+  /// 
+  /// Panics if `index` is outside the range `[0, len]`.
+  /// 
+  /// # Params
+  /// 
+  /// index --- The position to insert the value.  
+  /// value --- The element to insert.  
+  pub fn try_insert(&mut self, index: usize, value: T,) -> Result<(), CapacityError<T,>> {
+    assert!(index <= self.len, "`index` was greater than `len`",);
+    if self.len == N { return Err(CapacityError(value,)) }
+
+    //Safe because `index <= len < N` so the shifted tail stays within the buffer and the
+    //slot at `index` is overwritten without dropping the element moved out of it.
+    unsafe {
+      let ptr = (self.buffer.as_mut_ptr() as *mut T).add(index,);
+      ptr::copy(ptr, ptr.add(1,), self.len - index,);
+      ptr.write(value,);
+    }
+    self.len += 1; Ok(())
+  }
+  /// Inserts every element of `values` at `index`, preserving their order.
+  /// 
+  /// # Errors
+  /// 
+  /// Returns the iterator unconsumed if there is not enough space remaining.
+  /// 
+  /// # Panics
+  /// 
+  /// Panics if `index` is outside the range `[0, len]`.
+  /// 
+  /// # Params
+  /// 
+  /// index --- The position to insert the values.  
+  /// values --- The elements to insert.  
+  pub fn try_splice<I,>(&mut self, index: usize, values: I,) -> Result<(), CapacityError<I,>>
+    where I: ExactSizeIterator<Item = T>, {
+    assert!(index <= self.len, "`index` was greater than `len`",);
+    let count = values.len();
+    if N - self.len < count { return Err(CapacityError(values,)) }
+
+    let len = core::mem::replace(&mut self.len, index,);
+    let tail = len - index;
+    let mut written = 0;
+    //Safe because `len + count <= N` so the shifted tail stays within the buffer and `len`
+    //only covers the tail again once it has been moved back next to the written values.
+    unsafe {
+      let ptr = (self.buffer.as_mut_ptr() as *mut T).add(index,);
+      ptr::copy(ptr, ptr.add(count,), tail,);
+      //If `values` panics the tail is leaked rather than double dropped.
+      for value in values.take(count,) {
+        ptr.add(written,).write(value,);
+        written += 1;
+      }
+      //Close the gap left by an iterator which yielded fewer elements than it reported.
+      ptr::copy(ptr.add(count,), ptr.add(written,), tail,);
+    }
+    self.len = len + written; Ok(())
+  }
+  /// Removes and returns the element at `index`.
+  /// 
+  /// # Panics
+  /// 
+  /// Panics if `index` is outside the range `[0, len)`.
+  /// 
+  /// # Params
+  /// 
+  /// index --- The position of the element to remove.  
+  pub fn remove(&mut self, index: usize,) -> T {
+    assert!(index < self.len, "`index` was greater than `len`",);
+
+    self.len -= 1;
+    //Safe because `index < len` so the element at `index` is initialised, and it is read
+    //exactly once before the initialised tail is shifted down over it.
+    unsafe {
+      let ptr = (self.buffer.as_mut_ptr() as *mut T).add(index,);
+      let value = ptr.read();
+      ptr::copy(ptr.add(1,), ptr, self.len - index,);
+
+      value
+    }
+  }
+  /// Removes the elements in the range `[start, end)` and returns them in order.
+  /// 
+  /// # Panics
+  /// 
+  /// Panics if `start > end`, `end > len` or `end - start > M`.
+  /// 
+  /// # Params
+  /// 
+  /// start --- The index of the first element to remove.  
+  /// end --- The index after the last element to remove.  
+  pub fn remove_range<const M: usize,>(&mut self, start: usize, end: usize,) -> InlineVec<T, M,> {
+    assert!(start <= end && end <= self.len, "range was outside the range `[0, len]`",);
+    assert!(end - start <= M, "range was greater than the capacity of the output",);
+
+    let mut removed = InlineVec::new();
+    //Safe because `[start, end)` is initialised and fits in `removed`, and each element is
+    //moved exactly once before both lengths are updated.
+    unsafe {
+      let ptr = (self.buffer.as_mut_ptr() as *mut T).add(start,);
+      ptr::copy_nonoverlapping(ptr, removed.buffer.as_mut_ptr() as *mut T, end - start,);
+      ptr::copy(ptr.add(end - start,), ptr, self.len - end,);
+    }
+    removed.len = end - start;
+    self.len -= end - start;
+
+    removed
+  }
+  /// Drops every element, leaving the `InlineVec` empty.
+  pub fn clear(&mut self,) {
+    let len = core::mem::replace(&mut self.len, 0,);
+
+    //Safe because the first `len` slots are initialised and are no longer tracked.
+    unsafe { ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.buffer.as_mut_ptr() as *mut T, len,),) }
+  }
+  /// Moves every element out of the `InlineVec` in order, leaving it empty.
+  /// 
+  /// Any elements which are not consumed are dropped along with the returned iterator.
+  #[inline]
+  pub fn drain(&mut self,) -> Drain<'_, T,> { self.drain_from(0,) }
+  /// Moves the elements from `at` onwards out of the `InlineVec` in order.
+  /// 
+  /// Any elements which are not consumed are dropped along with the returned iterator.
+  /// 
+  /// # Panics
+  /// 
+  /// Panics if `at` is outside the range `[0, len]`.
+  /// 
+  /// # Params
+  /// 
+  /// at --- The index of the first element to move.  
+  pub fn drain_from(&mut self, at: usize,) -> Drain<'_, T,> {
+    assert!(at <= self.len, "`at` was greater than `len`",);
+    let len = core::mem::replace(&mut self.len, at,);
+
+    Drain(self.buffer[at..len].iter(),)
+  }
+}
+
+/// An iterator which moves elements out of an [`InlineVec`](crate::InlineVec).
+/// 
+/// The elements which are not consumed are dropped when the `Drain` is dropped.
+pub struct Drain<'a, T,>(slice::Iter<'a, MaybeUninit<T>>,);
+
+impl<T,> Iterator for Drain<'_, T,> {
+  type Item = T;
+
+  #[inline]
+  fn size_hint(&self,) -> (usize, Option<usize>,) { self.0.size_hint() }
+  #[inline]
+  fn next(&mut self,) -> Option<Self::Item> {
+    //Safe because the `InlineVec` no longer tracks these elements so each is read once.
+    self.0.next().map(|x,| unsafe { x.as_ptr().read() },)
+  }
+}
+
+impl<T,> DoubleEndedIterator for Drain<'_, T,> {
+  #[inline]
+  fn next_back(&mut self,) -> Option<Self::Item> {
+    //Safe because the `InlineVec` no longer tracks these elements so each is read once.
+    self.0.next_back().map(|x,| unsafe { x.as_ptr().read() },)
+  }
+}
+
+impl<T,> ExactSizeIterator for Drain<'_, T,> {}
+
+impl<T,> FusedIterator for Drain<'_, T,> {}
+
+impl<T,> Drop for Drain<'_, T,> {
+  fn drop(&mut self,) { self.for_each(drop,) }
+}
+
+impl<T,> fmt::Debug for Drain<'_, T,>
+  where T: fmt::Debug, {
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result {
+    //Safe because the remaining elements are initialised until they are read.
+    let rest = self.0.as_slice().iter().map(|x,| unsafe { &*x.as_ptr() },);
+
+    fmt.debug_tuple(stringify!(Drain),).field(&DebugList(rest,),).finish()
+  }
+}
+
+/// Formats the elements of a cloneable iterator as a list.
+struct DebugList<I,>(I,);
+
+impl<I,> fmt::Debug for DebugList<I,>
+  where I: Iterator + Clone,
+    I::Item: fmt::Debug, {
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result { fmt.debug_list().entries(self.0.clone(),).finish() }
+}
+
+impl<T, const N: usize,> Drop for InlineVec<T, N,> {
+  fn drop(&mut self,) { self.clear() }
+}
+
+impl<T, const N: usize,> Default for InlineVec<T, N,> {
+  #[inline]
+  fn default() -> Self { Self::new() }
+}
+
+impl<T, const N: usize,> Clone for InlineVec<T, N,>
+  where T: Clone, {
+  fn clone(&self,) -> Self {
+    let mut clone = Self::new();
+    for x in self.iter() {
+      //Cannot fail because `clone` has the same capacity as `self`.
+      let _ = clone.try_push(x.clone(),);
+    }
+
+    clone
+  }
+}
+
+impl<T, U, const N: usize, const M: usize,> PartialEq<InlineVec<U, M,>> for InlineVec<T, N,>
+  where T: PartialEq<U>, {
+  #[inline]
+  fn eq(&self, rhs: &InlineVec<U, M,>,) -> bool { self.as_slice() == rhs.as_slice() }
+}
+
+impl<T, U, const N: usize,> PartialEq<[U]> for InlineVec<T, N,>
+  where T: PartialEq<U>, {
+  #[inline]
+  fn eq(&self, rhs: &[U],) -> bool { self.as_slice() == rhs }
+}
+
+impl<T, const N: usize,> Eq for InlineVec<T, N,>
+  where T: Eq, {}
+
+impl<T, const N: usize,> Deref for InlineVec<T, N,> {
+  type Target = [T];
+
+  #[inline]
+  fn deref(&self,) -> &Self::Target { self.as_slice() }
+}
+
+impl<T, const N: usize,> DerefMut for InlineVec<T, N,> {
+  #[inline]
+  fn deref_mut(&mut self,) -> &mut Self::Target { self.as_mut_slice() }
+}
+
+impl<T, const N: usize,> fmt::Debug for InlineVec<T, N,>
+  where T: fmt::Debug, {
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result { fmt.debug_list().entries(self.iter(),).finish() }
+}
+
+#[cfg(test,)]
+mod tests {
+  use super::*;
+  use core::cell::Cell;
+
+  #[test]
+  fn test_inline_vec() {
+    let mut vec = InlineVec::<_, 4>::new();
+    assert_eq!(vec.try_push(1), Ok(()));
+    assert_eq!(vec.try_push(4), Ok(()));
+    assert_eq!(vec.try_insert(1, 3), Ok(()));
+    assert!(vec.try_splice(1, [2].iter().copied()).is_ok());
+    assert_eq!(vec.try_push(5), Err(CapacityError(5)));
+    assert_eq!(vec.as_slice(), [1, 2, 3, 4]);
+    assert_eq!(vec.remove_range::<2>(1, 3).as_slice(), [2, 3]);
+    assert_eq!(vec.remove(0), 1);
+    assert_eq!(vec.pop(), Some(4));
+    assert_eq!(vec.pop(), None);
+
+    let mut vec = InlineVec::<_, 3>::new();
+    assert_eq!(vec.try_push(1), Ok(()));
+    assert_eq!(vec.try_push(2), Ok(()));
+    assert_eq!(vec.try_push(3), Ok(()));
+    assert_eq!(vec.try_push(4), Err(CapacityError(4)));
+    assert_eq!(vec.as_slice(), [1, 2, 3]);
+    assert_eq!(vec.pop(), Some(3));
+    assert!(vec.drain_from(1).eq([2]));
+    assert!(vec.drain().eq([1]));
+    assert_eq!(vec.pop(), None);
+
+    struct Counted<'a,>(&'a Cell<usize>,);
+
+    impl Drop for Counted<'_,> {
+      fn drop(&mut self,) { self.0.set(self.0.get() + 1,) }
+    }
+
+    let drops = Cell::new(0,);
+    let mut vec = InlineVec::<_, 3>::new();
+    for _ in 0..3 { assert!(vec.try_push(Counted(&drops,),).is_ok()) }
+    let mut drain = vec.drain_from(1,);
+    drop(drain.next(),);
+    assert_eq!(drops.get(), 1);
+    drop(drain,);
+    assert_eq!(drops.get(), 2);
+    assert_eq!(vec.len(), 1);
+  }
+}
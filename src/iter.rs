@@ -1,12 +1,14 @@
 //! Author --- DMorgan  
 //! Last Moddified --- 2021-03-13
 
+#[cfg(feature = "alloc",)]
 use crate::Array;
+#[cfg(feature = "alloc",)]
 use alloc::alloc::Allocator;
 use core::{
   fmt,
   slice::Iter as SIter,
-  iter::{Copied, Iterator, IntoIterator, FusedIterator,},
+  iter::{Copied, Iterator, FusedIterator,},
 };
 
 /// An iterator over the ranks (row) of an [`Array`](crate::Array).
@@ -19,6 +21,22 @@ pub struct Iter<'a, T,> {
   elements: &'a [T],
 }
 
+impl<'a, T,> Iter<'a, T,> {
+  /// Creates a new `Iter` over `elements` split into ranks by `dimensions`.
+  /// 
+  /// # Params
+  /// 
+  /// dimensions --- The rank dimensions.  
+  /// elements --- The elements being referenced.  
+  pub(crate) fn new(dimensions: &'a [usize], elements: &'a [T],) -> Self {
+    Iter {
+      start_pos: 0,
+      dimensions: dimensions.iter().copied(),
+      elements,
+    }
+  }
+}
+
 impl<'a, T,> Iterator for Iter<'a, T,> {
   type Item = &'a [T];
 
@@ -60,18 +78,14 @@ impl<T,> fmt::Debug for Iter<'_, T,>
   }
 }
 
+#[cfg(feature = "alloc",)]
 impl<T, A,> Array<T, A,>
   where A: Allocator, {
   /// Returns an iterator over all of the ranks (rows) of this `Array`.
-  pub fn iter<'a,>(&'a self,) -> Iter<'a, T,> {
-    Iter {
-      start_pos: 0,
-      dimensions: self.dimensions.iter().copied(),
-      elements: &self.elements,
-    }
-  }
+  pub fn iter<'a,>(&'a self,) -> Iter<'a, T,> { Iter::new(&self.dimensions, &self.elements,) }
 }
 
+#[cfg(feature = "alloc",)]
 impl<'a, T, A,> IntoIterator for &'a Array<T, A,>
   where A: Allocator, {
   type IntoIter = Iter<'a, T,>;
@@ -81,7 +95,7 @@ impl<'a, T, A,> IntoIterator for &'a Array<T, A,>
   fn into_iter(self,) -> Self::IntoIter { self.iter() }
 }
 
-#[cfg(test,)]
+#[cfg(all(test, feature = "alloc",),)]
 mod tests {
   use super::*;
   use alloc::vec::Vec;
@@ -1,12 +1,14 @@
 //! Author --- DMorgan  
 //! Last Moddified --- 2021-03-13
 
+#[cfg(feature = "alloc",)]
 use crate::Array;
+#[cfg(feature = "alloc",)]
 use alloc::alloc::Allocator;
 use core::{
   fmt,
   slice::Iter as SIter,
-  iter::{Copied, Iterator, IntoIterator, FusedIterator,},
+  iter::{Copied, Iterator, FusedIterator,},
 };
 
 /// An iterator over the ranks (rows) of an [`Array`](crate::Array).
@@ -19,6 +21,22 @@ pub struct IterMut<'a, T: 'a,> {
   elements: *mut T,
 }
 
+impl<'a, T,> IterMut<'a, T,> {
+  /// Creates a new `IterMut` over `elements` split into ranks by `dimensions`.
+  /// 
+  /// # Params
+  /// 
+  /// dimensions --- The rank dimensions.  
+  /// elements --- The elements being referenced.  
+  pub(crate) fn new(dimensions: &'a [usize], elements: &'a mut [T],) -> Self {
+    IterMut {
+      start_pos: 0,
+      dimensions: dimensions.iter().copied(),
+      elements: elements.as_mut_ptr(),
+    }
+  }
+}
+
 impl<'a, T,> Iterator for IterMut<'a, T,> {
   type Item = &'a mut [T];
 
@@ -66,18 +84,14 @@ impl<T,> fmt::Debug for IterMut<'_, T,>
   }
 }
 
+#[cfg(feature = "alloc",)]
 impl<T, A,> Array<T, A,>
   where A: Allocator, {
   /// Returns a mutable iterator over all of the ranks (rows) of this `Array`.
-  pub fn iter_mut<'a,>(&'a mut self,) -> IterMut<'a, T,> {
-    IterMut {
-      start_pos: 0,
-      dimensions: self.dimensions.iter().copied(),
-      elements: self.elements.as_mut_ptr(),
-    }
-  }
+  pub fn iter_mut<'a,>(&'a mut self,) -> IterMut<'a, T,> { IterMut::new(&self.dimensions, &mut self.elements,) }
 }
 
+#[cfg(feature = "alloc",)]
 impl<'a, T, A,> IntoIterator for &'a mut Array<T, A,>
   where A: Allocator, {
   type IntoIter = IterMut<'a, T,>;
@@ -87,7 +101,7 @@ impl<'a, T, A,> IntoIterator for &'a mut Array<T, A,>
   fn into_iter(self,) -> Self::IntoIter { self.iter_mut() }
 }
 
-#[cfg(test,)]
+#[cfg(all(test, feature = "alloc",),)]
 mod tests {
   use super::*;
   use alloc::vec::Vec;
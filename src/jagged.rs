@@ -0,0 +1,354 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::CapacityError;
+#[cfg(feature = "alloc",)]
+use crate::{Array, Iter,};
+#[cfg(feature = "alloc",)]
+use alloc::{
+  vec::Vec,
+  alloc::Allocator,
+};
+use core::iter::FusedIterator;
+
+/// Read access to a jagged collection of elements laid out in ranks (rows) and files
+/// (columns).
+pub trait Jagged {
+  /// The type of the elements.
+  type Element;
+  /// The iterator returned by [`rank_iter`](Jagged::rank_iter).
+  type RankIter<'a,>: Iterator<Item = &'a [Self::Element]>
+    where Self: 'a;
+
+  /// Returns the number of ranks (rows).
+  fn ranks(&self,) -> usize;
+  /// A panic free way to index a rank.
+  /// 
+  /// # Params
+  /// 
+  /// rank --- The rank to reference.  
+  fn rank(&self, rank: usize,) -> Option<&[Self::Element]>;
+  /// Returns the number of files (columns) within the given `rank`.
+  /// 
+  /// Defaults to `0` for nonexistant ranks.
+  /// 
+  /// # Params
+  /// 
+  /// rank --- The rank to get the files for.  
+  fn files(&self, rank: usize,) -> usize { self.rank(rank,).map_or(0, <[_]>::len,) }
+  /// A panic free way to index an element.
+  /// 
+  /// # Params
+  /// 
+  /// index --- The rank and file of the element.  
+  fn get(&self, index: [usize; 2],) -> Option<&Self::Element> { self.rank(index[0],)?.get(index[1],) }
+  /// Returns the total number of elements across all ranks.
+  fn len(&self,) -> usize { (0..self.ranks()).map(|rank,| self.files(rank,),).sum() }
+  /// Returns `true` if there are no elements in any rank.
+  fn is_empty(&self,) -> bool { self.len() == 0 }
+  /// Returns an iterator over all of the ranks (rows).
+  /// 
+  /// # Complexity
+  /// 
+  /// Iterating every rank is O(R) where `R` is the number of ranks.
+  fn rank_iter(&self,) -> Self::RankIter<'_>;
+}
+
+/// Mutable access to a jagged collection of elements.
+pub trait JaggedMut: Jagged {
+  /// A panic free way to mutably index a rank.
+  /// 
+  /// # Params
+  /// 
+  /// rank --- The rank to reference.  
+  fn rank_mut(&mut self, rank: usize,) -> Option<&mut [Self::Element]>;
+  /// A panic free way to mutably index an element.
+  /// 
+  /// # Params
+  /// 
+  /// index --- The rank and file of the element.  
+  fn get_mut(&mut self, index: [usize; 2],) -> Option<&mut Self::Element> { self.rank_mut(index[0],)?.get_mut(index[1],) }
+}
+
+/// A jagged collection which can grow by appending ranks.
+pub trait JaggedPush: JaggedMut {
+  /// Appends a rank.
+  /// 
+  /// # Errors
+  /// 
+  /// Returns the unconsumed elements if there is not enough space for the rank; collections
+  /// which can allocate never fail.
+  /// 
+  /// # Params
+  /// 
+  /// rank --- The elements to append.  
+  fn push<I,>(&mut self, rank: I,) -> Result<(), CapacityError<I::IntoIter>>
+    where I: IntoIterator<Item = Self::Element>,
+      I::IntoIter: ExactSizeIterator;
+}
+
+#[cfg(feature = "alloc",)]
+impl<T, A,> Jagged for Array<T, A,>
+  where A: Allocator, {
+  type Element = T;
+  type RankIter<'a,> = Iter<'a, T,>
+    where Self: 'a;
+
+  #[inline]
+  fn ranks(&self,) -> usize { Array::ranks(self,) }
+  #[inline]
+  fn rank(&self, rank: usize,) -> Option<&[T]> { Array::rank(self, rank,) }
+  #[inline]
+  fn files(&self, rank: usize,) -> usize { Array::files(self, rank,) }
+  #[inline]
+  fn get(&self, index: [usize; 2],) -> Option<&T> { Array::get(self, index,) }
+  #[inline]
+  fn len(&self,) -> usize { Array::len(self,) }
+  #[inline]
+  fn rank_iter(&self,) -> Iter<'_, T,> { Array::iter(self,) }
+}
+
+#[cfg(feature = "alloc",)]
+impl<T, A,> JaggedMut for Array<T, A,>
+  where A: Allocator, {
+  #[inline]
+  fn rank_mut(&mut self, rank: usize,) -> Option<&mut [T]> { Array::rank_mut(self, rank,).map(Into::into,) }
+  #[inline]
+  fn get_mut(&mut self, index: [usize; 2],) -> Option<&mut T> { Array::get_mut(self, index,) }
+}
+
+#[cfg(feature = "alloc",)]
+impl<T, A,> JaggedPush for Array<T, A,>
+  where A: Allocator, {
+  fn push<I,>(&mut self, rank: I,) -> Result<(), CapacityError<I::IntoIter>>
+    where I: IntoIterator<Item = T>,
+      I::IntoIter: ExactSizeIterator, {
+    let len = self.elements.len();
+    self.elements.extend(rank,);
+    self.dimensions.push(self.elements.len() - len,);
+
+    Ok(())
+  }
+}
+
+/// An iterator over the ranks (rows) of any [`Jagged`](crate::Jagged) collection which
+/// indexes each rank through [`rank`](Jagged::rank).
+/// 
+/// This is only O(R) for collections which can index a rank in O(1).
+pub struct Ranks<'a, J: ?Sized,> {
+  /// The next rank to yield.
+  rank: usize,
+  /// The collection being referenced.
+  jagged: &'a J,
+}
+
+impl<'a, J,> Ranks<'a, J,>
+  where J: ?Sized, {
+  /// Creates a new `Ranks` starting from the first rank of `jagged`.
+  /// 
+  /// # Params
+  /// 
+  /// jagged --- The collection to iterate.  
+  #[inline]
+  pub fn new(jagged: &'a J,) -> Self { Ranks { rank: 0, jagged, } }
+}
+
+impl<'a, J,> Iterator for Ranks<'a, J,>
+  where J: Jagged + ?Sized, {
+  type Item = &'a [J::Element];
+
+  #[inline]
+  fn size_hint(&self,) -> (usize, Option<usize>,) {
+    let len = self.jagged.ranks().saturating_sub(self.rank,);
+
+    (len, Some(len),)
+  }
+  fn next(&mut self,) -> Option<Self::Item> {
+    let rank = self.jagged.rank(self.rank,)?;
+
+    self.rank += 1; Some(rank)
+  }
+}
+
+impl<J,> ExactSizeIterator for Ranks<'_, J,>
+  where J: Jagged + ?Sized, {}
+
+impl<J,> FusedIterator for Ranks<'_, J,>
+  where J: Jagged + ?Sized, {}
+
+impl<J,> Clone for Ranks<'_, J,>
+  where J: ?Sized, {
+  #[inline]
+  fn clone(&self,) -> Self { Ranks { rank: self.rank, jagged: self.jagged, } }
+}
+
+impl<J,> Jagged for &J
+  where J: Jagged + ?Sized, {
+  type Element = J::Element;
+  type RankIter<'a,> = J::RankIter<'a>
+    where Self: 'a;
+
+  #[inline]
+  fn ranks(&self,) -> usize { (**self).ranks() }
+  #[inline]
+  fn rank(&self, rank: usize,) -> Option<&[Self::Element]> { (**self).rank(rank,) }
+  #[inline]
+  fn files(&self, rank: usize,) -> usize { (**self).files(rank,) }
+  #[inline]
+  fn get(&self, index: [usize; 2],) -> Option<&Self::Element> { (**self).get(index,) }
+  #[inline]
+  fn len(&self,) -> usize { (**self).len() }
+  #[inline]
+  fn rank_iter(&self,) -> Self::RankIter<'_> { (**self).rank_iter() }
+}
+
+impl<J,> Jagged for &mut J
+  where J: Jagged + ?Sized, {
+  type Element = J::Element;
+  type RankIter<'a,> = J::RankIter<'a>
+    where Self: 'a;
+
+  #[inline]
+  fn ranks(&self,) -> usize { (**self).ranks() }
+  #[inline]
+  fn rank(&self, rank: usize,) -> Option<&[Self::Element]> { (**self).rank(rank,) }
+  #[inline]
+  fn files(&self, rank: usize,) -> usize { (**self).files(rank,) }
+  #[inline]
+  fn get(&self, index: [usize; 2],) -> Option<&Self::Element> { (**self).get(index,) }
+  #[inline]
+  fn len(&self,) -> usize { (**self).len() }
+  #[inline]
+  fn rank_iter(&self,) -> Self::RankIter<'_> { (**self).rank_iter() }
+}
+
+impl<J,> JaggedMut for &mut J
+  where J: JaggedMut + ?Sized, {
+  #[inline]
+  fn rank_mut(&mut self, rank: usize,) -> Option<&mut [Self::Element]> { (**self).rank_mut(rank,) }
+  #[inline]
+  fn get_mut(&mut self, index: [usize; 2],) -> Option<&mut Self::Element> { (**self).get_mut(index,) }
+}
+
+impl<T,> Jagged for [&[T]] {
+  type Element = T;
+  type RankIter<'a,> = Ranks<'a, Self,>
+    where Self: 'a;
+
+  #[inline]
+  fn ranks(&self,) -> usize { <[_]>::len(self,) }
+  #[inline]
+  fn rank(&self, rank: usize,) -> Option<&[T]> { <[_]>::get(self, rank,).copied() }
+  #[inline]
+  fn rank_iter(&self,) -> Ranks<'_, Self,> { Ranks::new(self,) }
+}
+
+impl<T,> Jagged for [&mut [T]] {
+  type Element = T;
+  type RankIter<'a,> = Ranks<'a, Self,>
+    where Self: 'a;
+
+  #[inline]
+  fn ranks(&self,) -> usize { <[_]>::len(self,) }
+  #[inline]
+  fn rank(&self, rank: usize,) -> Option<&[T]> { <[_]>::get(self, rank,).map(|rank,| &**rank,) }
+  #[inline]
+  fn rank_iter(&self,) -> Ranks<'_, Self,> { Ranks::new(self,) }
+}
+
+impl<T,> JaggedMut for [&mut [T]] {
+  #[inline]
+  fn rank_mut(&mut self, rank: usize,) -> Option<&mut [T]> { <[_]>::get_mut(self, rank,).map(|rank,| &mut **rank,) }
+}
+
+#[cfg(feature = "alloc",)]
+impl<T, A,> Jagged for [Vec<T, A>]
+  where A: Allocator, {
+  type Element = T;
+  type RankIter<'a,> = Ranks<'a, Self,>
+    where Self: 'a;
+
+  #[inline]
+  fn ranks(&self,) -> usize { <[_]>::len(self,) }
+  #[inline]
+  fn rank(&self, rank: usize,) -> Option<&[T]> { <[_]>::get(self, rank,).map(Vec::as_slice,) }
+  #[inline]
+  fn rank_iter(&self,) -> Ranks<'_, Self,> { Ranks::new(self,) }
+}
+
+#[cfg(feature = "alloc",)]
+impl<T, A,> JaggedMut for [Vec<T, A>]
+  where A: Allocator, {
+  #[inline]
+  fn rank_mut(&mut self, rank: usize,) -> Option<&mut [T]> { <[_]>::get_mut(self, rank,).map(Vec::as_mut_slice,) }
+}
+
+#[cfg(feature = "alloc",)]
+impl<R, A,> Jagged for Vec<R, A>
+  where [R]: Jagged,
+    A: Allocator, {
+  type Element = <[R] as Jagged>::Element;
+  type RankIter<'a,> = <[R] as Jagged>::RankIter<'a>
+    where Self: 'a;
+
+  #[inline]
+  fn ranks(&self,) -> usize { self.as_slice().ranks() }
+  #[inline]
+  fn rank(&self, rank: usize,) -> Option<&[Self::Element]> { Jagged::rank(self.as_slice(), rank,) }
+  #[inline]
+  fn rank_iter(&self,) -> Self::RankIter<'_> { self.as_slice().rank_iter() }
+}
+
+#[cfg(feature = "alloc",)]
+impl<R, A,> JaggedMut for Vec<R, A>
+  where [R]: JaggedMut,
+    A: Allocator, {
+  #[inline]
+  fn rank_mut(&mut self, rank: usize,) -> Option<&mut [Self::Element]> { JaggedMut::rank_mut(self.as_mut_slice(), rank,) }
+}
+
+#[cfg(all(test, feature = "alloc",),)]
+mod tests {
+  use super::*;
+
+  fn total<J,>(jagged: &J,) -> i32
+    where J: Jagged<Element = i32> + ?Sized, {
+    jagged.rank_iter().flatten().sum()
+  }
+
+  #[test]
+  fn test_jagged() {
+    let mut array = crate::array![[1, 2]; 1, 2, 3];
+    let mut vec = alloc::vec![alloc::vec![1], alloc::vec![2, 3]];
+    let slices = [&[1][..], &[2, 3][..]];
+    assert_eq!(total(&array), 6);
+    assert_eq!(total(&vec), 6);
+    assert_eq!(total(&slices[..]), 6);
+    assert_eq!(Jagged::len(&vec), 3);
+    assert_eq!(Jagged::len(&array), 3);
+    assert!(Jagged::rank_iter(&array).eq(array.iter()));
+    assert_eq!(Jagged::files(&slices[..], 1), 2);
+    assert_eq!(Jagged::get(&slices[..], [1, 0]), Some(&2));
+
+    fn bump<J: JaggedMut<Element = i32>,>(mut jagged: J,) {
+      *jagged.get_mut([1, 1]).expect("failed to get the element") += 1;
+    }
+    bump(&mut array);
+    bump(&mut vec);
+    assert_eq!(array, crate::array![[1, 2]; 1, 2, 4]);
+    assert_eq!(vec, [alloc::vec![1], alloc::vec![2, 4]]);
+
+    fn grow<J: JaggedPush<Element = i32>,>(jagged: &mut J,) -> bool {
+      jagged.push([5, 6],).is_ok() && jagged.push([],).is_ok()
+    }
+    let mut small = crate::SmallArray::<_, 2, 2>::new();
+    let mut fixed = crate::FixedArray::<_, 2, 2>::new();
+    assert!(grow(&mut array));
+    assert!(grow(&mut small));
+    assert!(grow(&mut fixed));
+    assert!(fixed.push([7],).is_err());
+    assert_eq!(array, crate::array![[1], [2, 4], [5, 6], []]);
+    assert_eq!(small.into_array(), crate::array![[5, 6], []]);
+    assert_eq!(fixed.rank(0), Some(&[5, 6][..]));
+  }
+}
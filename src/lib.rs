@@ -1,6 +1,12 @@
 //! A library which provides a `Vec`-like structure which is equivilant to a
 //! `Vec<Vec<T>>` type but with less allocations.
 //! 
+//! The `alloc` feature (enabled by default) provides the heap allocated types; without it
+//! only the fixed capacity [`FixedArray`](crate::FixedArray) is available. The `std`
+//! feature adds support for reading from and writing to `std::io` types and the `rkyv` feature adds zero
+//! copy archiving with [`rkyv`](https://docs.rs/rkyv). The `rayon` feature adds parallel
+//! counterparts to the per-rank aggregations using [`rayon`](https://docs.rs/rayon).
+//! 
 //! Author --- DMorgan  
 //! Last Moddified --- 2021-02-13
 
@@ -8,16 +14,69 @@
 #![deny(missing_docs,)]
 #![feature(allocator_api, type_alias_impl_trait, trusted_len, inplace_iteration, const_fn,)]
 
+#[cfg(feature = "alloc",)]
 extern crate alloc;
+#[cfg(feature = "std",)]
+extern crate std;
 
+#[cfg(feature = "alloc",)]
 #[doc(hidden,)]
 pub mod array;
+#[cfg(feature = "alloc",)]
 mod rank;
 mod iter;
 mod iter_mut;
+#[cfg(feature = "alloc",)]
 mod into_iter;
+mod inline_vec;
+mod jagged;
+mod fixed;
+mod mapped;
+#[cfg(feature = "alloc",)]
+mod small;
+#[cfg(feature = "alloc",)]
+mod shared;
+#[cfg(feature = "alloc",)]
+mod boxed;
+#[cfg(feature = "alloc",)]
+mod parse;
+#[cfg(feature = "alloc",)]
+mod sort;
+#[cfg(feature = "alloc",)]
+mod reorder;
+#[cfg(feature = "alloc",)]
+mod transpose;
+#[cfg(feature = "alloc",)]
+mod map;
+#[cfg(feature = "alloc",)]
+mod shape;
+#[cfg(feature = "alloc",)]
+mod aggregate;
+#[cfg(feature = "alloc",)]
+mod scan;
+#[cfg(feature = "alloc",)]
+mod group;
+#[cfg(feature = "alloc",)]
+mod segment;
+#[cfg(feature = "alloc",)]
+mod lookup;
+#[cfg(feature = "alloc",)]
+mod cursor;
+#[cfg(feature = "rkyv",)]
+mod archive;
+#[cfg(feature = "std",)]
+mod delimited;
 
-pub use self::{array::Array, rank::*, iter::*, iter_mut::*, into_iter::*,};
+pub use self::{iter::*, iter_mut::*, inline_vec::*, jagged::*, fixed::*, mapped::*,};
+#[cfg(feature = "alloc",)]
+pub use self::{array::Array, rank::*, transpose::*, shape::*, into_iter::*, small::*, shared::*, boxed::*, cursor::*,};
+#[cfg(feature = "alloc",)]
+pub use self::{parse::*, aggregate::ToF64,};
+#[cfg(feature = "rkyv",)]
+pub use self::archive::*;
+#[cfg(feature = "std",)]
+pub use self::delimited::*;
+#[cfg(feature = "alloc",)]
 #[doc(hidden,)]
 pub use alloc::vec;
 
@@ -47,9 +106,57 @@ pub use alloc::vec;
 /// assert_eq!(array![1; [2, 3]], array![[2, 3]; 1, 1, 1, 1, 1]);
 /// # }
 /// ```
+/// 
+/// Ranks can also be listed directly, without counting their elements by hand.
+/// 
+/// ```rust
+/// # #[macro_use] extern crate jagged_array; fn main() {
+/// use jagged_array::*;
+/// 
+/// assert_eq!(array![[1, 2], [3], [], [4, 5, 6]], array![[2, 1, 0, 3]; 1, 2, 3, 4, 5, 6]);
+/// # }
+/// ```
+/// 
+/// Prefixing the arguments with `in alloc;` constructs the `Array` in `alloc`.
+/// 
+/// ```rust
+/// # #![feature(allocator_api)]
+/// # #[macro_use] extern crate jagged_array; fn main() {
+/// use jagged_array::*;
+/// use std::alloc::Global;
+/// 
+/// let array: Array<i32, Global> = array![in Global; [1, 2], [3]];
+/// assert_eq!(array, array![in Global; [2, 1]; 1, 2, 3]);
+/// # }
+/// ```
+/// 
+/// Prefixing the dimensions with `const` checks them against the number of elements at
+/// compile time; the dimensions must then be constant expressions.
+/// 
+/// ```compile_fail
+/// # #[macro_use] extern crate jagged_array; fn main() {
+/// use jagged_array::*;
+/// 
+/// let array = array![const [2, 2]; 1, 2, 3];
+/// # }
+/// ```
+#[cfg(feature = "alloc",)]
 #[macro_export]
 macro_rules! array {
-  () => (Array::new());
+  (@unit $val:expr) => (());
+  (@count $($val:expr)*) => (<[()]>::len(&[$($crate::array!(@unit $val),)*]));
+  () => ($crate::Array::new());
+  (const [$($dim:expr),+]; $($val:expr),+ $(,)*) => ({
+    const _: () = assert!(0 $(+ $dim)+ == $crate::array!(@count $($val)+), "wrong number of elements",);
+    $crate::array::from_parts($crate::vec![$($dim,)+], $crate::vec![$($val,)+],)
+  });
+  (in $alloc:expr; [$($dim:expr),+]; $($val:expr),+ $(,)*) => ($crate::array::from_parts_in([$($dim,)+], [$($val,)+], $alloc,));
+  (in $alloc:expr; $([$($val:expr),* $(,)?]),+ $(,)?) => (
+    $crate::array::from_parts_in([$($crate::array!(@count $($val)*),)+], [$($($val,)*)+], $alloc,)
+  );
   ($val:expr; [$($dim:expr),+]) => ($crate::array::from_elem($val, $crate::vec![$($dim,)+],));
   ([$($dim:expr),+]; $($val:expr),+ $(,)*) => ($crate::array::from_parts($crate::vec![$($dim,)+], $crate::vec![$($val,)+],));
+  ($([$($val:expr),* $(,)?]),+ $(,)?) => (
+    $crate::array::from_parts($crate::vec![$($crate::array!(@count $($val)*),)+], $crate::vec![$($($val,)*)+],)
+  );
 }
@@ -0,0 +1,100 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::Array;
+use alloc::alloc::Allocator;
+
+impl<T, A,> Array<T, A,>
+  where A: Allocator, {
+  /// Binary searches a sorted rank for `x`, in the same way as `slice::binary_search`.
+  /// 
+  /// Returns `Ok` with the file of a matching element or `Err` with the file where `x`
+  /// could be inserted while keeping the rank sorted.
+  /// 
+  /// ```rust
+  /// use jagged_array::*;
+  /// 
+  /// let array = array![[1, 3, 5], [2, 4]];
+  /// assert_eq!(array.binary_search_in_rank(0, &3), Ok(1));
+  /// assert_eq!(array.binary_search_in_rank(1, &3), Err(1));
+  /// ```
+  /// 
+  /// # Panics
+  /// 
+  /// Panics if `rank` is out of bounds.
+  /// 
+  /// # Params
+  /// 
+  /// rank --- The rank to search.  
+  /// x --- The element to search for.  
+  pub fn binary_search_in_rank(&self, rank: usize, x: &T,) -> Result<usize, usize>
+    where T: Ord, {
+    self[rank].binary_search(x,)
+  }
+  /// Returns `true` if `rank` contains an element equal to `x`.
+  /// 
+  /// Returns `false` if `rank` does not exist.
+  /// 
+  /// # Params
+  /// 
+  /// rank --- The rank to search.  
+  /// x --- The element to search for.  
+  pub fn contains(&self, rank: usize, x: &T,) -> bool
+    where T: PartialEq, {
+    self.rank(rank,).is_some_and(|rank,| rank.contains(x,),)
+  }
+  /// Returns the rank and file of the first element, in rank order, which matches `pred`.
+  /// 
+  /// ```rust
+  /// use jagged_array::*;
+  /// 
+  /// let array = array![[1, 3], [], [4, 6]];
+  /// assert_eq!(array.position(|&x| x % 2 == 0), Some([2, 0]));
+  /// ```
+  /// 
+  /// # Params
+  /// 
+  /// pred --- Returns `true` for the element being searched for.  
+  pub fn position(&self, mut pred: impl FnMut(&T,) -> bool,) -> Option<[usize; 2]> {
+    self.iter().enumerate()
+    .find_map(|(rank, files,),| files.iter().position(&mut pred,).map(|file,| [rank, file],),)
+  }
+  /// Returns the index of the first rank which matches `pred`.
+  /// 
+  /// # Params
+  /// 
+  /// pred --- Returns `true` for the rank being searched for.  
+  pub fn find_rank(&self, pred: impl FnMut(&[T],) -> bool,) -> Option<usize> {
+    self.iter().position(pred,)
+  }
+  /// Returns the rank and file of the element at `flat` in
+  /// [`as_flat_slice`](Array::as_flat_slice), or `None` if `flat` is out of bounds.
+  /// 
+  /// This is the same as [`coords`](Array::coords).
+  /// 
+  /// # Params
+  /// 
+  /// flat --- The index of the element.  
+  #[inline]
+  pub fn rank_of_flat_index(&self, flat: usize,) -> Option<[usize; 2]> { self.coords(flat,) }
+}
+
+#[cfg(test,)]
+mod tests {
+  #[test]
+  fn test_lookup_array() {
+    let array = crate::array![[1, 4, 9], [], [], [2, 8]];
+    assert_eq!(array.binary_search_in_rank(0, &4,), Ok(1));
+    assert_eq!(array.binary_search_in_rank(3, &5,), Err(1));
+    assert_eq!(array.binary_search_in_rank(1, &5,), Err(0));
+    assert!(array.contains(3, &8,));
+    assert!(!array.contains(0, &8,));
+    assert!(!array.contains(4, &8,));
+    assert_eq!(array.position(|&x,| x > 4,), Some([0, 2]));
+    assert_eq!(array.position(|&x,| x > 9,), None);
+    assert_eq!(array.find_rank(<[_]>::is_empty,), Some(1));
+
+    for flat in 0..6 { assert_eq!(array.rank_of_flat_index(flat,), array.coords(flat,)) }
+    assert_eq!(array.rank_of_flat_index(3,), Some([3, 0]));
+  }
+}
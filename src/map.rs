@@ -0,0 +1,215 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::Array;
+use alloc::{
+  vec::Vec,
+  alloc::Allocator,
+};
+use core::{
+  ptr, mem,
+  convert::Infallible,
+  marker::PhantomData,
+  mem::ManuallyDrop,
+};
+
+/// Maps every element of `elements` with `f`, stopping at the first error.
+/// 
+/// The allocation of `elements` is reused when `T` and `U` have the same size and
+/// alignment.
+/// 
+/// # Params
+/// 
+/// elements --- The elements to map.  
+/// f --- The mapping to apply.  
+fn try_map_elements<T, U, E, A,>(elements: Vec<T, A>, mut f: impl FnMut(T,) -> Result<U, E>,) -> Result<Vec<U, A>, E>
+  where A: Allocator + Clone, {
+  if mem::size_of::<T>() != mem::size_of::<U>() || mem::align_of::<T>() != mem::align_of::<U>() {
+    let mut mapped = Vec::with_capacity_in(elements.len(), elements.allocator().clone(),);
+    for element in elements { mapped.push(f(element,)?,) }
+
+    return Ok(mapped)
+  }
+
+  /// Owns a buffer which is part way through being mapped in place.
+  struct Guard<T, U, A: Allocator,> {
+    /// The buffer, with its length set to `0`.
+    elements: Vec<T, A>,
+    /// The number of elements in the buffer.
+    len: usize,
+    /// The number of elements which have been mapped.
+    mapped: usize,
+    /// The mapped element type.
+    _marker: PhantomData<U>,
+  }
+
+  impl<T, U, A: Allocator,> Drop for Guard<T, U, A,> {
+    fn drop(&mut self,) {
+      let ptr = self.elements.as_mut_ptr();
+
+      //Safe because the elements before `mapped` have been mapped and the element at
+      //`mapped` has been moved out to be mapped.
+      unsafe {
+        ptr::drop_in_place(ptr::slice_from_raw_parts_mut(ptr as *mut U, self.mapped,),);
+        ptr::drop_in_place(ptr::slice_from_raw_parts_mut(ptr.add(self.mapped + 1,), self.len - self.mapped - 1,),);
+      }
+    }
+  }
+
+  let len = elements.len();
+  let mut guard = Guard { elements, len, mapped: 0, _marker: PhantomData::<U>, };
+  //Safe because `guard` now tracks which elements are initialised.
+  unsafe { guard.elements.set_len(0,) }
+  let ptr = guard.elements.as_mut_ptr();
+  while guard.mapped < len {
+    //Safe because `T` and `U` have the same layout and each element is moved out once.
+    unsafe {
+      let element = f(ptr.add(guard.mapped,).read(),)?;
+      (ptr.add(guard.mapped,) as *mut U).write(element,);
+    }
+    guard.mapped += 1;
+  }
+
+  let guard = ManuallyDrop::new(guard,);
+  //Safe because every element has been mapped and the buffer has the layout of `U`.
+  unsafe {
+    let mut elements = ManuallyDrop::new(ptr::read(&guard.elements,),);
+    let alloc = ptr::read(elements.allocator(),);
+
+    Ok(Vec::from_raw_parts_in(elements.as_mut_ptr() as *mut U, len, elements.capacity(), alloc,))
+  }
+}
+
+/// Unwraps a `Result` which cannot fail.
+/// 
+/// # Params
+/// 
+/// result --- The result to unwrap.  
+#[inline]
+fn unwrap<T,>(result: Result<T, Infallible>,) -> T {
+  match result {
+    Ok(value,) => value,
+    Err(never,) => match never {},
+  }
+}
+
+impl<T, A,> Array<T, A,>
+  where A: Allocator + Clone, {
+  /// Maps every element of the `Array` with `f`, preserving its shape.
+  /// 
+  /// ```rust
+  /// use jagged_array::*;
+  /// 
+  /// let array = array![[1, 2], [], [3]];
+  /// assert_eq!(array.map(|x| x as u8 * 2), array![[2u8, 4], [], [6]]);
+  /// ```
+  /// 
+  /// # Complexity
+  /// 
+  /// O(N) where `N is Array.len`. The element buffer is reused when `T` and `U` have the
+  /// same size and alignment and `dimensions` is always reused.
+  /// 
+  /// # Params
+  /// 
+  /// f --- The mapping to apply.  
+  pub fn map<U,>(self, mut f: impl FnMut(T,) -> U,) -> Array<U, A,> {
+    unwrap(self.try_map(|x,| Ok(f(x,),),),)
+  }
+  /// Maps every element of the `Array` with `f`, stopping at the first error.
+  /// 
+  /// # Errors
+  /// 
+  /// Returns the first error returned by `f`.
+  /// 
+  /// # Complexity
+  /// 
+  /// O(N) where `N is Array.len`. The element buffer is reused when `T` and `U` have the
+  /// same size and alignment and `dimensions` is always reused.
+  /// 
+  /// # Params
+  /// 
+  /// f --- The mapping to apply.  
+  pub fn try_map<U, E,>(self, f: impl FnMut(T,) -> Result<U, E>,) -> Result<Array<U, A,>, E> {
+    Ok(Array { dimensions: self.dimensions, elements: try_map_elements(self.elements, f,)?, })
+  }
+  /// Maps every element of the `Array` with `f`, which is also passed the rank and file of
+  /// the element.
+  /// 
+  /// # Complexity
+  /// 
+  /// O(N + R) where `N is Array.len` and `R is Array.ranks`. The element buffer is reused
+  /// when `T` and `U` have the same size and alignment and `dimensions` is always reused.
+  /// 
+  /// # Params
+  /// 
+  /// f --- The mapping to apply.  
+  pub fn map_indexed<U,>(self, mut f: impl FnMut([usize; 2], T,) -> U,) -> Array<U, A,> {
+    let (mut rank, mut file,) = (0, 0,);
+    let dimensions = &self.dimensions;
+    let elements = unwrap(try_map_elements(self.elements, |x,| {
+      //Skip past finished and empty ranks; there is always a rank left for `x`.
+      while file == dimensions[rank] { rank += 1; file = 0 }
+      file += 1;
+
+      Ok(f([rank, file - 1], x,),)
+    },),);
+
+    Array { dimensions: self.dimensions, elements, }
+  }
+  /// Maps a reference to every element of the `Array` with `f`, preserving its shape.
+  /// 
+  /// # Params
+  /// 
+  /// f --- The mapping to apply.  
+  pub fn map_ref<U,>(&self, f: impl FnMut(&T,) -> U,) -> Array<U, A,> {
+    let mut elements = Vec::with_capacity_in(self.len(), self.allocator().clone(),);
+    elements.extend(self.elements.iter().map(f,),);
+
+    Array { dimensions: self.dimensions.clone(), elements, }
+  }
+  /// Combines every element of the `Array` with the element at the same position in
+  /// `other` using `f`.
+  /// 
+  /// # Panics
+  /// 
+  /// Panics if `other` does not have the same rank lengths as the `Array`.
+  /// 
+  /// # Complexity
+  /// 
+  /// O(N + R) where `N is Array.len` and `R is Array.ranks`. The element buffer is reused
+  /// when `T` and `V` have the same size and alignment and `dimensions` is always reused.
+  /// 
+  /// # Params
+  /// 
+  /// other --- The `Array` to combine with.  
+  /// f --- Combines a pair of elements.  
+  pub fn zip_with<U, V, A2,>(self, other: &Array<U, A2,>, mut f: impl FnMut(T, &U,) -> V,) -> Array<V, A,>
+    where A2: Allocator, {
+    assert!(self.same_shape(other,), "`other` does not have the same shape as the `Array`",);
+
+    let mut other = other.elements.iter();
+    //Cannot fail because `other` has the same number of elements.
+    self.map(|x,| f(x, other.next().expect("`other` ran out of elements",),),)
+  }
+}
+
+#[cfg(test,)]
+mod tests {
+  use alloc::string::{String, ToString,};
+
+  #[test]
+  fn test_map_array() {
+    let array = crate::array![[1, 2], [], [3]];
+    let ptr = array.elements.as_ptr() as usize;
+
+    let array = array.map(|x,| x as u32 * 2,);
+    assert_eq!(array.elements.as_ptr() as usize, ptr);
+    assert_eq!(array, crate::array![[2u32, 4], [], [6]]);
+    assert_eq!(array.map_ref(ToString::to_string,), crate::array![[String::from("2"), String::from("4")], [], [String::from("6")]]);
+    assert_eq!(array.clone().try_map(|x,| if x < 4 { Ok(x) } else { Err(x) },), Err(4));
+
+    let array = array.map_indexed(|[rank, file], x,| rank * 10 + file + x as usize,);
+    assert_eq!(array, crate::array![[2, 5], [], [26]]);
+    assert_eq!(array.zip_with(&crate::array![[1u8, 2], [], [3]], |x, &y,| x * y as usize,), crate::array![[2, 10], [], [78]]);
+  }
+}
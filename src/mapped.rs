@@ -0,0 +1,342 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::{Jagged, Ranks,};
+use core::{
+  fmt, mem,
+  ops::Index,
+  borrow::Borrow,
+  convert::TryInto,
+};
+
+/// The magic bytes at the start of every serialized jagged array.
+pub const MAGIC: [u8; 8] = *b"JAGARRAY";
+/// The current version of the serialized jagged array layout.
+pub const FORMAT_VERSION: u32 = 1;
+/// The value written to detect a byte order mismatch.
+const BYTE_ORDER: u32 = 0x0102_0304;
+/// The size of the header in bytes.
+const HEADER_LEN: usize = 40;
+/// The alignment of the element block relative to the start of the buffer.
+const ELEMENT_ALIGN: usize = 16;
+
+/// Types which can be safely reinterpreted from and written as raw bytes.
+/// 
+/// # Safety
+/// 
+/// Implementors must have no padding and every bit pattern must be a valid value.
+pub unsafe trait Pod: Copy + 'static {}
+
+macro_rules! impl_pod {
+  ($($ty:ty),* $(,)*) => ($(unsafe impl Pod for $ty {})*);
+}
+
+impl_pod!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64,);
+
+/// Returns the offset of the element block for an array with `ranks` ranks.
+/// 
+/// # Params
+/// 
+/// ranks --- The number of ranks in the array.  
+fn element_offset(ranks: usize,) -> Option<usize> {
+  let end = ranks.checked_mul(mem::size_of::<u64>(),)?.checked_add(HEADER_LEN,)?;
+
+  end.checked_add(ELEMENT_ALIGN - 1,).map(|end,| end / ELEMENT_ALIGN * ELEMENT_ALIGN,)
+}
+
+#[cfg(feature = "std",)]
+impl<T, A,> crate::Array<T, A,>
+  where T: Pod,
+    A: alloc::alloc::Allocator, {
+  /// Writes this `Array` to `writer` in the layout read by
+  /// [`MappedArray`](crate::MappedArray).
+  /// 
+  /// # Params
+  /// 
+  /// writer --- The destination of the serialized bytes.  
+  /// 
+  /// ```rust
+  /// # fn main() -> std::io::Result<()> {
+  /// use jagged_array::*;
+  /// 
+  /// let array = array![[2, 1]; 1u32, 2, 3];
+  /// let mut bytes = Vec::new();
+  /// array.write_to(&mut bytes)?;
+  /// 
+  /// //Copy into a buffer with a suitable alignment.
+  /// let mut aligned = vec![0u64; bytes.len() / 8 + 1];
+  /// let buffer = unsafe { std::slice::from_raw_parts_mut(aligned.as_mut_ptr() as *mut u8, bytes.len()) };
+  /// buffer.copy_from_slice(&bytes);
+  /// 
+  /// let mapped = MappedArray::<u32>::new(buffer).unwrap();
+  /// assert_eq!(mapped[1], [3]);
+  /// # Ok(()) }
+  /// ```
+  pub fn write_to(&self, mut writer: impl std::io::Write,) -> std::io::Result<()> {
+    writer.write_all(&MAGIC,)?;
+    writer.write_all(&FORMAT_VERSION.to_ne_bytes(),)?;
+    writer.write_all(&BYTE_ORDER.to_ne_bytes(),)?;
+    writer.write_all(&(mem::size_of::<T>() as u32).to_ne_bytes(),)?;
+    writer.write_all(&(mem::align_of::<T>() as u32).to_ne_bytes(),)?;
+    writer.write_all(&(self.ranks() as u64).to_ne_bytes(),)?;
+    writer.write_all(&(self.len() as u64).to_ne_bytes(),)?;
+
+    let mut end = 0u64;
+    for &files in self.dimensions.iter() {
+      end += files as u64;
+      writer.write_all(&end.to_ne_bytes(),)?;
+    }
+
+    let start = HEADER_LEN + self.ranks() * mem::size_of::<u64>();
+    let padding = element_offset(self.ranks(),).expect("`Array` is too large to serialize") - start;
+    writer.write_all(&[0; ELEMENT_ALIGN][..padding],)?;
+
+    //Safe because `Pod` types have no padding bytes.
+    let elements = unsafe {
+      core::slice::from_raw_parts(self.elements.as_ptr() as *const u8, mem::size_of_val(&*self.elements,),)
+    };
+    writer.write_all(elements,)
+  }
+}
+
+/// The error returned when a byte buffer does not contain a valid serialized jagged array.
+#[derive(PartialEq, Eq, Clone, Copy, Debug,)]
+pub enum MapError {
+  /// The buffer is shorter than the layout it describes.
+  TooShort,
+  /// The buffer does not start with [`MAGIC`](crate::MAGIC).
+  BadMagic,
+  /// The buffer was written with an unsupported layout version.
+  UnsupportedVersion(u32,),
+  /// The buffer was written on a machine with a different byte order.
+  ByteOrder,
+  /// The buffer was written with a different element type.
+  ElementType {
+    /// The size of the elements in the buffer.
+    size: u32,
+    /// The alignment of the elements in the buffer.
+    align: u32,
+  },
+  /// The buffer is not sufficiently aligned for the offsets or elements.
+  Misaligned,
+  /// The offsets table is not monotonic or does not end at the element count.
+  BadOffsets,
+}
+
+impl fmt::Display for MapError {
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result {
+    match self {
+      MapError::TooShort => write!(fmt, "buffer is too short",),
+      MapError::BadMagic => write!(fmt, "buffer is not a serialized jagged array",),
+      MapError::UnsupportedVersion(version,) => write!(fmt, "unsupported layout version `{}`", version,),
+      MapError::ByteOrder => write!(fmt, "buffer was written with a different byte order",),
+      MapError::ElementType { size, align, } => write!(fmt, "buffer elements have size `{}` and alignment `{}`", size, align,),
+      MapError::Misaligned => write!(fmt, "buffer is not sufficiently aligned",),
+      MapError::BadOffsets => write!(fmt, "buffer offsets are out of bounds",),
+    }
+  }
+}
+
+#[cfg(feature = "std",)]
+impl std::error::Error for MapError {}
+
+/// A jagged array borrowed directly from a serialized byte buffer, such as a memory map.
+/// 
+/// # Layout
+/// 
+/// All integers are stored in the byte order of the machine which wrote them.
+/// 
+/// | Offset | Size | Contents |
+/// | --- | --- | --- |
+/// | 0 | 8 | [`MAGIC`](crate::MAGIC) |
+/// | 8 | 4 | [`FORMAT_VERSION`](crate::FORMAT_VERSION) |
+/// | 12 | 4 | `0x01020304` as a byte order check |
+/// | 16 | 4 | The size of an element |
+/// | 20 | 4 | The alignment of an element |
+/// | 24 | 8 | The number of ranks, `R` |
+/// | 32 | 8 | The number of elements, `N` |
+/// | 40 | `8 * R` | The end offset of each rank as a `u64` |
+/// | | | Zero padding up to a multiple of 16 bytes |
+/// | | `N * size` | The elements |
+/// 
+/// The buffer must be aligned to at least 8 bytes and to the alignment of `T`.
+pub struct MappedArray<'a, T,> {
+  /// The end offset of each rank within `elements`.
+  offsets: &'a [u64],
+  /// The elements of the `MappedArray`.
+  elements: &'a [T],
+}
+
+impl<'a, T,> MappedArray<'a, T,>
+  where T: Pod, {
+  /// Interprets `bytes` as a serialized jagged array, validating its header, alignment and
+  /// offsets.
+  /// 
+  /// # Params
+  /// 
+  /// bytes --- The serialized jagged array.  
+  pub fn new(bytes: &'a [u8],) -> Result<Self, MapError> {
+    fn read_u32(bytes: &[u8], at: usize,) -> u32 { u32::from_ne_bytes(bytes[at..at + 4].try_into().unwrap(),) }
+    fn read_u64(bytes: &[u8], at: usize,) -> u64 { u64::from_ne_bytes(bytes[at..at + 8].try_into().unwrap(),) }
+
+    if bytes.len() < HEADER_LEN { return Err(MapError::TooShort) }
+    if bytes[..8] != MAGIC { return Err(MapError::BadMagic) }
+
+    let version = read_u32(bytes, 8,);
+    if version != FORMAT_VERSION {
+      return Err(if version.swap_bytes() == FORMAT_VERSION { MapError::ByteOrder } else { MapError::UnsupportedVersion(version,) })
+    }
+    if read_u32(bytes, 12,) != BYTE_ORDER { return Err(MapError::ByteOrder) }
+
+    let (size, align,) = (read_u32(bytes, 16,), read_u32(bytes, 20,),);
+    if size as usize != mem::size_of::<T>() || align as usize != mem::align_of::<T>() {
+      return Err(MapError::ElementType { size, align, })
+    }
+
+    let align = mem::align_of::<T>().max(mem::align_of::<u64>(),);
+    if bytes.as_ptr() as usize & (align - 1) != 0 || mem::align_of::<T>() > ELEMENT_ALIGN { return Err(MapError::Misaligned) }
+
+    let ranks = read_u64(bytes, 24,).try_into().map_err(|_,| MapError::TooShort,)?;
+    let len: usize = read_u64(bytes, 32,).try_into().map_err(|_,| MapError::TooShort,)?;
+    let start = element_offset(ranks,).ok_or(MapError::TooShort,)?;
+    let end = len.checked_mul(mem::size_of::<T>(),).and_then(|size,| size.checked_add(start,),).ok_or(MapError::TooShort,)?;
+    if bytes.len() < end { return Err(MapError::TooShort) }
+
+    //Safe because the buffer is long enough and aligned for both the offsets and elements.
+    let (offsets, elements,) = unsafe {
+      let ptr = bytes.as_ptr();
+      (
+        core::slice::from_raw_parts(ptr.add(HEADER_LEN,) as *const u64, ranks,),
+        core::slice::from_raw_parts(ptr.add(start,) as *const T, len,),
+      )
+    };
+
+    let monotonic = offsets.windows(2,).all(|pair,| pair[0] <= pair[1],);
+    if !monotonic || offsets.last().map_or(0, |&end,| end,) != len as u64 { return Err(MapError::BadOffsets) }
+
+    Ok(Self { offsets, elements, })
+  }
+}
+
+impl<'a, T,> MappedArray<'a, T,> {
+  /// Returns the number of elements in the `MappedArray`.
+  #[inline]
+  pub fn len(&self,) -> usize { self.elements.len() }
+  /// Returns `true` if the `MappedArray` contains no elements.
+  #[inline]
+  pub fn is_empty(&self,) -> bool { self.elements.is_empty() }
+  /// Returns the number of ranks (rows) making up the `MappedArray`.
+  #[inline]
+  pub fn ranks(&self,) -> usize { self.offsets.len() }
+  /// Returns the number of files (columns) within the given `rank`.
+  /// 
+  /// Defaults to `0` for nonexistant ranks.
+  /// 
+  /// # Params
+  /// 
+  /// rank --- The rank to get the files for.  
+  pub fn files(&self, rank: usize,) -> usize { self.rank(rank,).map_or(0, <[_]>::len,) }
+  /// A panic free way to index a rank of a `MappedArray`.
+  /// 
+  /// # Params
+  /// 
+  /// rank --- The rank to reference.  
+  pub fn rank(&self, rank: usize,) -> Option<&'a [T]> {
+    let end = *self.offsets.get(rank,)? as usize;
+    let start = rank.checked_sub(1,).map_or(0, |rank,| self.offsets[rank] as usize,);
+
+    Some(&self.elements[start..end])
+  }
+  /// A panic free way to index a `MappedArray`.
+  /// 
+  /// # Params
+  /// 
+  /// index --- The rank and file of the element.  
+  pub fn get(&self, index: impl Borrow<[usize; 2]>,) -> Option<&'a T> {
+    let index = index.borrow();
+
+    self.rank(index[0],)?.get(index[1],)
+  }
+  /// Returns an iterator over all of the ranks (rows) of this `MappedArray`.
+  #[inline]
+  pub fn iter(&self,) -> Ranks<'_, Self,> { Ranks::new(self,) }
+}
+
+impl<T,> Clone for MappedArray<'_, T,> {
+  #[inline]
+  fn clone(&self,) -> Self { *self }
+}
+
+impl<T,> Copy for MappedArray<'_, T,> {}
+
+impl<T,> Index<usize> for MappedArray<'_, T,> {
+  type Output = [T];
+
+  fn index(&self, index: usize,) -> &Self::Output {
+    self.rank(index,).expect("`index` is not within the `MappedArray` bounds")
+  }
+}
+
+impl<T,> Index<[usize; 2]> for MappedArray<'_, T,> {
+  type Output = T;
+
+  fn index(&self, index: [usize; 2],) -> &Self::Output {
+    self.get(index,).expect("`index` is not within the `MappedArray` bounds")
+  }
+}
+
+impl<T,> Jagged for MappedArray<'_, T,> {
+  type Element = T;
+  type RankIter<'b,> = Ranks<'b, Self,>
+    where Self: 'b;
+
+  #[inline]
+  fn ranks(&self,) -> usize { MappedArray::ranks(self,) }
+  #[inline]
+  fn rank(&self, rank: usize,) -> Option<&[T]> { MappedArray::rank(self, rank,) }
+  #[inline]
+  fn len(&self,) -> usize { MappedArray::len(self,) }
+  #[inline]
+  fn rank_iter(&self,) -> Ranks<'_, Self,> { Ranks::new(self,) }
+}
+
+impl<T,> fmt::Debug for MappedArray<'_, T,>
+  where T: fmt::Debug, {
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result {
+    fmt.debug_list()
+    .entries(self.iter(),)
+    .finish()
+  }
+}
+
+#[cfg(all(test, feature = "std",),)]
+mod tests {
+  use super::*;
+  use alloc::vec::Vec;
+
+  #[test]
+  fn test_mapped_array() {
+    let array = crate::array![[2, 0, 3]; 1u16, 2, 3, 4, 5];
+    let mut bytes = Vec::new();
+    array.write_to(&mut bytes).expect("failed to write the array");
+    assert_eq!(bytes.len(), 64 + 10);
+
+    let mut aligned = alloc::vec![0u64; bytes.len() / 8 + 1];
+    let buffer = unsafe { core::slice::from_raw_parts_mut(aligned.as_mut_ptr() as *mut u8, bytes.len()) };
+    buffer.copy_from_slice(&bytes);
+
+    let mapped = MappedArray::<u16>::new(buffer).expect("failed to map the array");
+    assert_eq!(mapped.len(), 5);
+    assert_eq!(mapped.ranks(), 3);
+    assert_eq!(mapped.files(1), 0);
+    assert_eq!(mapped.get([2, 2]), Some(&5));
+    assert_eq!(mapped.iter().collect::<Vec<_>>(), array.iter().collect::<Vec<_>>());
+
+    assert_eq!(MappedArray::<u32>::new(buffer).err(), Some(MapError::ElementType { size: 2, align: 2 }));
+    assert_eq!(MappedArray::<u16>::new(&buffer[..70]).err(), Some(MapError::TooShort));
+    assert_eq!(MappedArray::<u16>::new(&buffer[1..]).err(), Some(MapError::BadMagic));
+
+    buffer[40..48].copy_from_slice(&9u64.to_ne_bytes());
+    assert_eq!(MappedArray::<u16>::new(buffer).err(), Some(MapError::BadOffsets));
+  }
+}
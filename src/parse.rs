@@ -0,0 +1,207 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::Array;
+use core::{
+  fmt,
+  str::FromStr,
+};
+
+/// A token expected by the parser.
+#[derive(PartialEq, Eq, Clone, Copy, Debug,)]
+pub enum ExpectedToken {
+  /// An opening bracket, `[`.
+  Open,
+  /// The start of a rank or the end of the array, `[` or `]`.
+  OpenOrClose,
+  /// An element or the end of a rank, `]`.
+  ElementOrClose,
+  /// A separator or the end of the list, `,` or `]`.
+  CommaOrClose,
+  /// The end of the input.
+  End,
+}
+
+impl fmt::Display for ExpectedToken {
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result {
+    match self {
+      ExpectedToken::Open => write!(fmt, "`[`",),
+      ExpectedToken::OpenOrClose => write!(fmt, "`[` or `]`",),
+      ExpectedToken::ElementOrClose => write!(fmt, "an element or `]`",),
+      ExpectedToken::CommaOrClose => write!(fmt, "`,` or `]`",),
+      ExpectedToken::End => write!(fmt, "the end of the input",),
+    }
+  }
+}
+
+/// The error returned when parsing an [`Array`](crate::Array) from a string fails.
+#[derive(PartialEq, Eq, Clone, Debug,)]
+pub enum ParseArrayError<E,> {
+  /// The input did not contain the expected token.
+  Expected {
+    /// The byte offset of the unexpected input.
+    offset: usize,
+    /// The token which was expected.
+    token: ExpectedToken,
+  },
+  /// An element failed to parse.
+  Element {
+    /// The byte offset of the element.
+    offset: usize,
+    /// The error returned when parsing the element.
+    error: E,
+  },
+}
+
+impl<E,> ParseArrayError<E,> {
+  /// Returns the byte offset in the input at which the error occured.
+  #[inline]
+  pub fn offset(&self,) -> usize {
+    match self {
+      ParseArrayError::Expected { offset, .. } | ParseArrayError::Element { offset, .. } => *offset,
+    }
+  }
+}
+
+impl<E,> fmt::Display for ParseArrayError<E,>
+  where E: fmt::Display, {
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result {
+    match self {
+      ParseArrayError::Expected { offset, token, } => write!(fmt, "expected {} at byte {}", token, offset,),
+      ParseArrayError::Element { offset, error, } => write!(fmt, "invalid element at byte {}: {}", offset, error,),
+    }
+  }
+}
+
+#[cfg(feature = "std",)]
+impl<E,> std::error::Error for ParseArrayError<E,>
+  where E: std::error::Error + 'static, {
+  fn source(&self,) -> Option<&(dyn std::error::Error + 'static)> {
+    match self {
+      ParseArrayError::Expected { .. } => None,
+      ParseArrayError::Element { error, .. } => Some(error,),
+    }
+  }
+}
+
+/// A cursor over the input being parsed.
+struct Parser<'a,> {
+  /// The input.
+  input: &'a str,
+  /// The byte offset of the cursor.
+  offset: usize,
+}
+
+impl<'a,> Parser<'a,> {
+  /// Returns the next character without consuming it, skipping any whitespace.
+  fn peek(&mut self,) -> Option<char> {
+    let rest = &self.input[self.offset..];
+    let trimmed = rest.trim_start();
+    self.offset += rest.len() - trimmed.len();
+
+    trimmed.chars().next()
+  }
+  /// Consumes the next character if it is `c`, skipping any whitespace.
+  /// 
+  /// # Params
+  /// 
+  /// c --- The character to consume.  
+  fn eat(&mut self, c: char,) -> bool {
+    let eaten = self.peek() == Some(c,);
+    if eaten { self.offset += c.len_utf8() }
+
+    eaten
+  }
+  /// Returns an error expecting `token` at the cursor.
+  /// 
+  /// # Params
+  /// 
+  /// token --- The token which was expected.  
+  fn expected<E,>(&self, token: ExpectedToken,) -> ParseArrayError<E,> { ParseArrayError::Expected { offset: self.offset, token, } }
+  /// Consumes the text of the next element, skipping any surrounding whitespace.
+  fn element(&mut self,) -> &'a str {
+    self.peek();
+    let rest = &self.input[self.offset..];
+    let len = rest.find([',', '[', ']'],).unwrap_or(rest.len(),);
+    self.offset += len;
+
+    rest[..len].trim_end()
+  }
+}
+
+impl<T,> FromStr for Array<T,>
+  where T: FromStr, {
+  type Err = ParseArrayError<T::Err,>;
+
+  /// Parses an `Array` from a nested list such as `[[1], [2, 3]]`.
+  /// 
+  /// Whitespace is allowed between tokens and each list may have a trailing comma.
+  /// Elements are the text between separators with surrounding whitespace removed.
+  /// 
+  /// ```rust
+  /// use jagged_array::*;
+  /// 
+  /// let array = "[[1, 2], [],\n [3,],]".parse::<Array<i32>>().unwrap();
+  /// assert_eq!(array, array![[2, 0, 1]; 1, 2, 3]);
+  /// assert_eq!(array.to_string().parse::<Array<i32>>(), Ok(array));
+  /// 
+  /// let error = "[[1, 2] [3]]".parse::<Array<i32>>().unwrap_err();
+  /// assert_eq!(error, ParseArrayError::Expected { offset: 8, token: ExpectedToken::CommaOrClose });
+  /// ```
+  fn from_str(input: &str,) -> Result<Self, Self::Err> {
+    let mut parser = Parser { input, offset: 0, };
+    let mut array = Array::new();
+
+    if !parser.eat('[',) { return Err(parser.expected(ExpectedToken::Open,)) }
+    while !parser.eat(']',) {
+      if !parser.eat('[',) { return Err(parser.expected(ExpectedToken::OpenOrClose,)) }
+
+      let mut files = 0;
+      while !parser.eat(']',) {
+        let offset = parser.offset;
+        let element = parser.element();
+        if element.is_empty() { return Err(parser.expected(ExpectedToken::ElementOrClose,)) }
+
+        let element = element.parse().map_err(|error,| ParseArrayError::Element { offset, error, },)?;
+        array.elements.push(element,);
+        files += 1;
+
+        if parser.eat(',',) { continue }
+        if parser.peek() != Some(']',) { return Err(parser.expected(ExpectedToken::CommaOrClose,)) }
+      }
+      array.dimensions.push(files,);
+
+      if parser.eat(',',) { continue }
+      if parser.peek() != Some(']',) { return Err(parser.expected(ExpectedToken::CommaOrClose,)) }
+    }
+    if parser.peek().is_some() { return Err(parser.expected(ExpectedToken::End,)) }
+
+    Ok(array)
+  }
+}
+
+#[cfg(test,)]
+mod tests {
+  use super::*;
+  use alloc::{format, string::ToString,};
+
+  #[test]
+  fn test_parse_array() {
+    let array = " [ [ 1 ,2 , ] ,[],[ 3 ] ] ".parse::<Array<i32>>().expect("failed to parse the array");
+    assert_eq!(array, crate::array![[2, 0, 1]; 1, 2, 3]);
+    assert_eq!(array.to_string(), "[[1, 2], [], [3]]");
+    assert_eq!(format!("{:?}", array).parse::<Array<i32>>(), Ok(array));
+    assert_eq!("[]".parse::<Array<i32>>(), Ok(Array::new()));
+
+    let expected = |offset, token,| Err(ParseArrayError::Expected { offset, token, });
+    assert_eq!("".parse::<Array<i32>>(), expected(0, ExpectedToken::Open));
+    assert_eq!("[1]".parse::<Array<i32>>(), expected(1, ExpectedToken::OpenOrClose));
+    assert_eq!("[[1,,2]]".parse::<Array<i32>>(), expected(4, ExpectedToken::ElementOrClose));
+    assert_eq!("[[1 [2]]]".parse::<Array<i32>>(), expected(4, ExpectedToken::CommaOrClose));
+    assert_eq!("[[1]".parse::<Array<i32>>(), expected(4, ExpectedToken::CommaOrClose));
+    assert_eq!("[[1]] x".parse::<Array<i32>>(), expected(6, ExpectedToken::End));
+
+    let error = "[[1], [2, x]]".parse::<Array<i32>>().expect_err("parsed an invalid element");
+    assert!(matches!(error, ParseArrayError::Element { offset: 10, .. }));
+  }
+}
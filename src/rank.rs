@@ -2,7 +2,10 @@
 //! Last Moddified --- 2021-03-13
 
 use crate::Array;
-use alloc::alloc::{Allocator, Global,};
+use alloc::{
+  vec::Vec,
+  alloc::{Allocator, Global,},
+};
 use core::{
   fmt,
   ops::{Deref, DerefMut,},
@@ -100,6 +103,75 @@ impl<T, A,> Rank<'_, T, A,>
     *files = files.checked_sub(1,)?;
     Some(self.array.elements.remove(self.start_pos + *files,))
   }
+  /// Splits the rank at `file`, moving the elements from `file` onwards into a new rank
+  /// directly after it.
+  /// 
+  /// The `Rank` keeps referencing the front of the split.
+  /// 
+  /// # Complexity
+  /// 
+  /// O(R) where `R is Array.ranks`; no elements are moved.
+  /// 
+  /// # Panics
+  /// 
+  /// Panics if `file` is outside the range `[0, len]`.
+  /// 
+  /// # Params
+  /// 
+  /// file --- The index within the rank to split at.  
+  pub fn split_off(&mut self, file: usize,) {
+    let files = self.array.dimensions[self.rank];
+    assert!(file <= files, "`file` was greater than `len`",);
+
+    self.array.dimensions[self.rank] = file;
+    self.array.dimensions.insert(self.rank + 1, files - file,);
+  }
+  /// Appends the elements of the next rank to this rank and removes the next rank.
+  /// 
+  /// Returns `false`, leaving the `Array` unchanged, if this is the last rank.
+  /// 
+  /// # Complexity
+  /// 
+  /// O(R) where `R is Array.ranks`; no elements are moved.
+  pub fn merge_next(&mut self,) -> bool {
+    if self.rank + 1 >= self.array.ranks() { return false }
+
+    let files = self.array.dimensions.remove(self.rank + 1,);
+    self.array.dimensions[self.rank] += files;
+    true
+  }
+  /// Moves the `Rank` to the next rank of the inner `Array`.
+  /// 
+  /// Returns `None` if this is the last rank.
+  /// 
+  /// # Complexity
+  /// 
+  /// O(1)
+  pub fn next(self,) -> Option<Self> {
+    if self.rank + 1 >= self.array.ranks() { return None }
+
+    let start_pos = self.start_pos + self.array.dimensions[self.rank];
+    Some(Rank { rank: self.rank + 1, start_pos, array: self.array, })
+  }
+  /// Moves the `Rank` to the previous rank of the inner `Array`.
+  /// 
+  /// Returns `None` if this is the first rank.
+  /// 
+  /// # Complexity
+  /// 
+  /// O(1)
+  pub fn prev(self,) -> Option<Self> {
+    let rank = self.rank.checked_sub(1,)?;
+    let start_pos = self.start_pos - self.array.dimensions[rank];
+
+    Some(Rank { rank, start_pos, array: self.array, })
+  }
+  /// Removes the rank from the inner `Array` and returns its elements.
+  /// 
+  /// # Complexity
+  /// 
+  /// O(N + R) where `N is Array.len` and `R is Array.ranks`.
+  pub fn remove_self(self,) -> Vec<T> { self.array.remove(self.rank,) }
 }
 
 impl<T, A,> PartialEq for Rank<'_, T, A,>
@@ -206,12 +278,40 @@ impl<T, A,> Array<T, A,>
   /// 
   /// rank --- The rank to reference.  
   pub fn rank_mut<'a,>(&'a mut self, rank: usize,) -> Option<Rank<'a, T, A,>> {
+    if rank >= self.ranks() { return None }
+
     Some(Rank {
       rank,
-      start_pos: self.get_element_index(&[rank, 0,],)?,
+      //Safe because `rank` is in bounds and the start of a rank is valid even when it is
+      //empty.
+      start_pos: unsafe { self.get_element_index_unchecked(&[rank, 0,],) },
       array: self,
     })
   }
+  /// Returns the rank at `rank`, first appending empty ranks until it exists.
+  /// 
+  /// ```rust
+  /// use jagged_array::*;
+  /// 
+  /// let mut array = array![[1]];
+  /// array.rank_or_extend(2).push(2);
+  /// 
+  /// assert_eq!(array, array![[1], [], [2]]);
+  /// ```
+  /// 
+  /// # Complexity
+  /// 
+  /// O(R) where `R` is `rank`; only `dimensions` is touched by the new ranks.
+  /// 
+  /// # Params
+  /// 
+  /// rank --- The rank to reference.  
+  pub fn rank_or_extend(&mut self, rank: usize,) -> Rank<'_, T, A,> {
+    if rank >= self.ranks() { self.resize_ranks(rank + 1,) }
+
+    //Safe because the rank was just created if it did not exist.
+    Rank { rank, start_pos: unsafe { self.get_element_index_unchecked(&[rank, 0,],) }, array: self, }
+  }
 }
 
 #[cfg(test,)]
@@ -237,5 +337,39 @@ mod tests {
     rank.extend([6, 7].iter());
     assert_eq!(rank, [6, 7][..]);
     assert_eq!(array, crate::array![[3, 2]; 1, 4, 5, 6, 7]);
+
+    let mut rank = array.rank_mut(0,).expect("failed to get the rank");
+    rank.split_off(1,);
+    assert_eq!(rank, [1][..]);
+    let mut rank = rank.next().expect("failed to get the next rank");
+    assert_eq!(rank, [4, 5][..]);
+    assert!(rank.merge_next());
+    assert_eq!(rank, [4, 5, 6, 7][..]);
+    assert!(!rank.merge_next());
+    rank.split_off(4,);
+    let rank = rank.next().expect("failed to get the next rank");
+    assert!(rank.is_empty());
+    assert!(rank.next().is_none());
+    assert_eq!(array, crate::array![[1], [4, 5, 6, 7], []]);
+
+    //Empty ranks which exist are returned rather than treated as out of bounds.
+    assert!(array.rank_mut(2,).is_some_and(|rank,| rank.is_empty(),));
+    assert!(array.rank_mut(3,).is_none());
+    let rank = array.rank_mut(2,).expect("failed to get an empty rank");
+    let rank = rank.prev().expect("failed to get the previous rank");
+    assert_eq!(rank.remove_self(), [4, 5, 6, 7]);
+    assert_eq!(array, crate::array![[1], []]);
+
+    array.rank_or_extend(3,).push(8,);
+    array.rank_or_extend(1,).push(9,);
+    assert_eq!(array, crate::array![[1], [9], [], [8]]);
+    array.resize_ranks(5,);
+    assert_eq!(array.dimensions(), [1, 1, 0, 1, 0]);
+    array.resize_ranks(2,);
+    assert_eq!(array, crate::array![[1], [9]]);
+    array.resize_ranks_with(4, || alloc::vec![0],);
+    assert_eq!(array, crate::array![[1], [9], [0], [0]]);
+    array.resize_ranks_with(1, alloc::vec::Vec::new,);
+    assert_eq!(array, crate::array![[1]]);
   }
 }
@@ -0,0 +1,146 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::Array;
+use alloc::alloc::Allocator;
+
+impl<T, A,> Array<T, A,>
+  where A: Allocator, {
+  /// Reorders the ranks so that rank `permutation[i]` becomes rank `i`.
+  /// 
+  /// ```rust
+  /// use jagged_array::*;
+  /// 
+  /// let mut array = array![[1], [2, 3], []];
+  /// array.permute_ranks(&[2, 0, 1]);
+  /// 
+  /// assert_eq!(array, array![[], [1], [2, 3]]);
+  /// ```
+  /// 
+  /// # Complexity
+  /// 
+  /// O(N + R) where `N is Array.len` and `R is Array.ranks`, using one temporary buffer of
+  /// `N` elements.
+  /// 
+  /// # Panics
+  /// 
+  /// Panics if `permutation` is not a permutation of `[0, ranks)`.
+  /// 
+  /// # Params
+  /// 
+  /// permutation --- The rank to move into each position.  
+  pub fn permute_ranks(&mut self, permutation: &[usize],) {
+    assert!(permutation.len() == self.ranks(), "`permutation` does not have `ranks` entries",);
+    let mut seen = alloc::vec![false; permutation.len()];
+    for &rank in permutation {
+      assert!(rank < seen.len() && !seen[rank], "`permutation` is not a permutation of the ranks",);
+      seen[rank] = true;
+    }
+
+    //Safe because we just validated `permutation`.
+    unsafe { self.reorder_ranks(permutation,) }
+  }
+  /// Swaps two ranks.
+  /// 
+  /// # Complexity
+  /// 
+  /// O(N) where `N` is the number of elements from the start of the first rank to the
+  /// end of the second; no buffer is allocated.
+  /// 
+  /// # Panics
+  /// 
+  /// Panics if `a` or `b` is outside the range `[0, ranks)`.
+  /// 
+  /// # Params
+  /// 
+  /// a --- The first rank to swap.  
+  /// b --- The second rank to swap.  
+  pub fn swap_ranks(&mut self, a: usize, b: usize,) {
+    assert!(a < self.ranks() && b < self.ranks(), "rank was greater than `ranks`",);
+    let (a, b,) = if a <= b { (a, b,) } else { (b, a,) };
+    if a == b { return }
+
+    //Safe because we just asserted that both ranks are within the bounds of `ranks`.
+    let start = unsafe { self.get_element_index_unchecked(&[a, 0],) };
+    let (first, last,) = (self.dimensions[a], self.dimensions[b],);
+    let middle = self.dimensions[a + 1..b].iter().sum::<usize>();
+    let span = &mut self.elements[start..start + first + middle + last];
+    //[a][middle][b] -> [middle][b][a] -> [b][middle][a]
+    span.rotate_left(first,);
+    span[..middle + last].rotate_left(middle,);
+
+    self.dimensions.swap(a, b,);
+  }
+  /// Reverses the order of the ranks.
+  /// 
+  /// # Complexity
+  /// 
+  /// O(N + R) where `N is Array.len` and `R is Array.ranks`; no buffer is allocated.
+  pub fn reverse_ranks(&mut self,) {
+    self.elements.reverse();
+    self.dimensions.reverse();
+    //Every rank is now in the right place but with its elements reversed.
+    for rank in self.iter_mut() { rank.reverse() }
+  }
+  /// Rotates the ranks so that rank `mid` becomes the first rank.
+  /// 
+  /// # Complexity
+  /// 
+  /// O(N + R) where `N is Array.len` and `R is Array.ranks`; no buffer is allocated.
+  /// 
+  /// # Panics
+  /// 
+  /// Panics if `mid` is outside the range `[0, ranks]`.
+  /// 
+  /// # Params
+  /// 
+  /// mid --- The number of ranks to rotate by.  
+  pub fn rotate_ranks_left(&mut self, mid: usize,) {
+    assert!(mid <= self.ranks(), "`mid` was greater than `ranks`",);
+
+    let start = self.dimensions[..mid].iter().sum::<usize>();
+    self.elements.rotate_left(start,);
+    self.dimensions.rotate_left(mid,);
+  }
+  /// Rotates the ranks so that the last `k` ranks become the first ranks.
+  /// 
+  /// # Complexity
+  /// 
+  /// O(N + R) where `N is Array.len` and `R is Array.ranks`; no buffer is allocated.
+  /// 
+  /// # Panics
+  /// 
+  /// Panics if `k` is outside the range `[0, ranks]`.
+  /// 
+  /// # Params
+  /// 
+  /// k --- The number of ranks to rotate by.  
+  pub fn rotate_ranks_right(&mut self, k: usize,) {
+    assert!(k <= self.ranks(), "`k` was greater than `ranks`",);
+
+    self.rotate_ranks_left(self.ranks() - k,)
+  }
+}
+
+#[cfg(test,)]
+mod tests {
+  #[test]
+  fn test_reorder_ranks() {
+    let mut array = crate::array![[1], [2, 3], [], [4, 5, 6]];
+    array.swap_ranks(3, 0);
+    assert_eq!(array, crate::array![[4, 5, 6], [2, 3], [], [1]]);
+    array.swap_ranks(1, 2);
+    assert_eq!(array, crate::array![[4, 5, 6], [], [2, 3], [1]]);
+
+    array.reverse_ranks();
+    assert_eq!(array, crate::array![[1], [2, 3], [], [4, 5, 6]]);
+
+    array.rotate_ranks_left(1);
+    assert_eq!(array, crate::array![[2, 3], [], [4, 5, 6], [1]]);
+    array.rotate_ranks_right(1);
+    assert_eq!(array, crate::array![[1], [2, 3], [], [4, 5, 6]]);
+
+    array.permute_ranks(&[3, 1, 0, 2]);
+    assert_eq!(array, crate::array![[4, 5, 6], [2, 3], [1], []]);
+  }
+}
@@ -0,0 +1,128 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::Array;
+use alloc::{
+  vec::Vec,
+  alloc::Allocator,
+};
+use core::{
+  mem,
+  cmp::Ordering,
+  ops::AddAssign,
+};
+
+/// Returns the file of the first element of `rank` which compares as `ordering` against
+/// every other element, or `None` if `rank` is empty.
+/// 
+/// # Params
+/// 
+/// rank --- The elements to search.  
+/// ordering --- `Greater` to find the largest element or `Less` to find the smallest.  
+fn arg_extreme<T,>(rank: &[T], ordering: Ordering,) -> Option<usize>
+  where T: Ord, {
+  let mut best = 0;
+  for (file, x,) in rank.iter().enumerate().skip(1,) {
+    if x.cmp(&rank[best],) == ordering { best = file }
+  }
+
+  if rank.is_empty() { None } else { Some(best,) }
+}
+
+impl<T, A,> Array<T, A,>
+  where A: Allocator, {
+  /// Computes an inclusive scan of each rank; every element of the result is `f` applied
+  /// to the previous result in the rank, starting from `init`, and the element.
+  /// 
+  /// ```rust
+  /// use jagged_array::*;
+  /// 
+  /// let array = array![[1, 2, 3], [], [4, 5]];
+  /// assert_eq!(array.scan_each_rank(0, |acc, x| acc + x), array![[1, 3, 6], [], [4, 9]]);
+  /// ```
+  /// 
+  /// # Params
+  /// 
+  /// init --- The initial value of each rank.  
+  /// f --- Combines the previous result with the next element.  
+  pub fn scan_each_rank<B,>(&self, init: B, mut f: impl FnMut(&B, &T,) -> B,) -> Array<B, A,>
+    where B: Clone,
+      A: Clone, {
+    let mut elements = Vec::with_capacity_in(self.len(), self.allocator().clone(),);
+    for rank in self.iter() {
+      let mut acc = init.clone();
+      for x in rank {
+        acc = f(&acc, x,);
+        elements.push(acc.clone(),);
+      }
+    }
+
+    Array { dimensions: self.dimensions.clone(), elements, }
+  }
+  /// Computes an exclusive scan of each rank; every element of the result is the result of
+  /// the inclusive scan at the previous file, or `init` for the first file.
+  /// 
+  /// ```rust
+  /// use jagged_array::*;
+  /// 
+  /// let array = array![[1, 2, 3], [], [4, 5]];
+  /// assert_eq!(array.exclusive_scan_each_rank(0, |acc, x| acc + x), array![[0, 1, 3], [], [0, 4]]);
+  /// ```
+  /// 
+  /// # Params
+  /// 
+  /// init --- The initial value of each rank.  
+  /// f --- Combines the previous result with the next element.  
+  pub fn exclusive_scan_each_rank<B,>(&self, init: B, mut f: impl FnMut(&B, &T,) -> B,) -> Array<B, A,>
+    where B: Clone,
+      A: Clone, {
+    let mut elements = Vec::with_capacity_in(self.len(), self.allocator().clone(),);
+    for rank in self.iter() {
+      let mut acc = init.clone();
+      for x in rank {
+        let next = f(&acc, x,);
+        elements.push(mem::replace(&mut acc, next,),);
+      }
+    }
+
+    Array { dimensions: self.dimensions.clone(), elements, }
+  }
+  /// Replaces every element with the sum of the elements up to and including it within its
+  /// rank.
+  pub fn cumsum_each_rank(&mut self,)
+    where T: Copy + AddAssign, {
+    for rank in self.iter_mut() {
+      for file in 1..rank.len() {
+        let previous = rank[file - 1];
+        rank[file] += previous;
+      }
+    }
+  }
+  /// Returns the file of the first largest element of each rank, yielding `None` for empty
+  /// ranks.
+  pub fn argmax_ranks(&self,) -> Vec<Option<usize>>
+    where T: Ord, {
+    self.iter().map(|rank,| arg_extreme(rank, Ordering::Greater,),).collect()
+  }
+  /// Returns the file of the first smallest element of each rank, yielding `None` for empty
+  /// ranks.
+  pub fn argmin_ranks(&self,) -> Vec<Option<usize>>
+    where T: Ord, {
+    self.iter().map(|rank,| arg_extreme(rank, Ordering::Less,),).collect()
+  }
+}
+
+#[cfg(test,)]
+mod tests {
+  #[test]
+  fn test_scan_ranks() {
+    let mut array = crate::array![[3, 1, 3], [], [2, 5, 1, 5]];
+    assert_eq!(array.argmax_ranks(), [Some(0), None, Some(1)]);
+    assert_eq!(array.argmin_ranks(), [Some(1), None, Some(2)]);
+    assert_eq!(array.scan_each_rank(1, |acc, x,| acc * x,), crate::array![[3, 3, 9], [], [2, 10, 10, 50]]);
+    assert_eq!(array.exclusive_scan_each_rank(1, |acc, x,| acc * x,), crate::array![[1, 3, 3], [], [1, 2, 10, 10]]);
+
+    array.cumsum_each_rank();
+    assert_eq!(array, crate::array![[3, 4, 7], [], [2, 7, 8, 13]]);
+  }
+}
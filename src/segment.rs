@@ -0,0 +1,159 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::{Array, ShapeError,};
+use alloc::{
+  vec::Vec,
+  alloc::Allocator,
+};
+
+impl<T, A,> Array<T, A,>
+  where A: Allocator + Clone, {
+  /// Creates a new `Array` by splitting `elements` into ranks of `size` elements, the last
+  /// of which may be shorter.
+  /// 
+  /// ```rust
+  /// use jagged_array::*;
+  /// 
+  /// let array = Array::from_chunks(vec![1, 2, 3, 4, 5], 2);
+  /// assert_eq!(array, array![[1, 2], [3, 4], [5]]);
+  /// ```
+  /// 
+  /// # Panics
+  /// 
+  /// Panics if `size` is `0`.
+  /// 
+  /// # Complexity
+  /// 
+  /// O(R) where `R` is the number of ranks; the allocation of `elements` is reused.
+  /// 
+  /// # Params
+  /// 
+  /// elements --- The elements of the `Array`.  
+  /// size --- The number of elements in each rank.  
+  pub fn from_chunks(elements: Vec<T, A>, size: usize,) -> Self {
+    assert!(size != 0, "chunk size must be non-zero",);
+
+    let len = elements.len();
+    let mut dimensions = Vec::with_capacity_in(len.div_ceil(size,), elements.allocator().clone(),);
+    dimensions.extend((0..len).step_by(size,).map(|start,| size.min(len - start,),),);
+
+    Self { dimensions, elements, }
+  }
+  /// Creates a new `Array` by splitting `elements` on every element which matches `pred`,
+  /// removing the separators, in the same way as `slice::split`.
+  /// 
+  /// ```rust
+  /// use jagged_array::*;
+  /// 
+  /// let array = Array::from_split(vec![1, 0, 2, 3, 0, 0, 4], |&x| x == 0);
+  /// assert_eq!(array, array![[1], [2, 3], [], [4]]);
+  /// ```
+  /// 
+  /// # Complexity
+  /// 
+  /// O(N) where `N` is the number of elements; the allocation of `elements` is reused.
+  /// 
+  /// # Params
+  /// 
+  /// elements --- The elements of the `Array`, including the separators.  
+  /// pred --- Returns `true` for a separator.  
+  pub fn from_split(mut elements: Vec<T, A>, mut pred: impl FnMut(&T,) -> bool,) -> Self {
+    let mut dimensions = Vec::new_in(elements.allocator().clone(),);
+    dimensions.push(0,);
+    elements.retain(|x,| {
+      if pred(x,) { dimensions.push(0,); return false }
+
+      //Cannot fail because `dimensions` always has a rank.
+      if let Some(files,) = dimensions.last_mut() { *files += 1 }
+      true
+    },);
+
+    Self { dimensions, elements, }
+  }
+  /// Creates a new `Array` by splitting `elements` at each offset in `boundaries`, giving
+  /// one more rank than there are boundaries.
+  /// 
+  /// ```rust
+  /// use jagged_array::*;
+  /// 
+  /// let array = Array::from_boundaries(vec![1, 2, 3, 4], &[1, 1, 3]);
+  /// assert_eq!(array, array![[1], [], [2, 3], [4]]);
+  /// ```
+  /// 
+  /// # Panics
+  /// 
+  /// Panics if `boundaries` is not sorted or any boundary is greater than
+  /// `elements.len()`.
+  /// 
+  /// # Complexity
+  /// 
+  /// O(B) where `B` is the number of boundaries; the allocation of `elements` is reused.
+  /// 
+  /// # Params
+  /// 
+  /// elements --- The elements of the `Array`.  
+  /// boundaries --- The offsets at which each new rank starts.  
+  pub fn from_boundaries(elements: Vec<T, A>, boundaries: &[usize],) -> Self {
+    let mut dimensions = Vec::with_capacity_in(boundaries.len() + 1, elements.allocator().clone(),);
+    let mut start = 0;
+    for &end in boundaries.iter().chain(Some(&elements.len(),),) {
+      assert!(start <= end && end <= elements.len(), "boundary `{}` is out of order or out of bounds", end,);
+
+      dimensions.push(end - start,);
+      start = end;
+    }
+
+    Self { dimensions, elements, }
+  }
+  /// Creates a new `Array` from `elements` where each rank has the corresponding length in
+  /// `lengths`.
+  /// 
+  /// # Errors
+  /// 
+  /// Returns an error if `lengths` does not describe `elements.len()` elements.
+  /// 
+  /// # Complexity
+  /// 
+  /// O(R) where `R` is the number of ranks; the allocation of `elements` is reused.
+  /// 
+  /// # Params
+  /// 
+  /// elements --- The elements of the `Array`.  
+  /// lengths --- The number of elements in each rank.  
+  pub fn from_lengths(elements: Vec<T, A>, lengths: &[usize],) -> Result<Self, ShapeError> {
+    let (expected, found,) = (lengths.iter().sum(), elements.len(),);
+    if expected != found { return Err(ShapeError { expected, found, }) }
+
+    let mut dimensions = Vec::with_capacity_in(lengths.len(), elements.allocator().clone(),);
+    dimensions.extend_from_slice(lengths,);
+
+    Ok(Self { dimensions, elements, })
+  }
+}
+
+#[cfg(test,)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_segment_array() {
+    let elements = alloc::vec![1, 2, 3, 4, 5, 6, 7];
+    let ptr = elements.as_ptr() as usize;
+
+    let array = Array::from_chunks(elements, 3,);
+    assert_eq!(array.elements.as_ptr() as usize, ptr);
+    assert_eq!(array, crate::array![[1, 2, 3], [4, 5, 6], [7]]);
+    assert_eq!(Array::from_chunks(Vec::<i32>::new(), 3,).ranks(), 0);
+
+    let array = Array::from_split(array.elements, |&x,| x % 3 == 0,);
+    assert_eq!(array.elements.as_ptr() as usize, ptr);
+    assert_eq!(array, crate::array![[1, 2], [4, 5], [7]]);
+    assert_eq!(Array::from_split(alloc::vec![0], |&x,| x == 0,).dimensions(), [0, 0]);
+
+    let array = Array::from_boundaries(array.elements, &[0, 2],);
+    assert_eq!(array, crate::array![[], [1, 2], [4, 5, 7]]);
+    assert_eq!(Array::from_lengths(array.elements.clone(), &[4, 1],), Ok(crate::array![[1, 2, 4, 5], [7]]));
+    assert_eq!(Array::from_lengths(array.elements, &[4],), Err(ShapeError { expected: 4, found: 5, }));
+  }
+}
@@ -0,0 +1,203 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::Array;
+use alloc::{
+  vec::Vec,
+  alloc::Allocator,
+};
+use core::fmt;
+
+/// The shape of a jagged array; the number of files (columns) in each rank (row).
+/// 
+/// ```rust
+/// use jagged_array::*;
+/// 
+/// let shape = array![[1, 2], [], [3]].shape();
+/// assert_eq!(shape.dimensions(), [2, 0, 1]);
+/// assert_eq!(shape.offsets(), [0, 2, 2, 3]);
+/// ```
+#[derive(PartialEq, Eq, Hash, Clone, Default, Debug,)]
+pub struct Shape {
+  /// The number of files in each rank.
+  dimensions: Vec<usize>,
+}
+
+impl Shape {
+  /// Creates a new `Shape` from the number of files in each rank.
+  /// 
+  /// # Params
+  /// 
+  /// dimensions --- The number of files in each rank.  
+  #[inline]
+  pub const fn new(dimensions: Vec<usize>,) -> Self { Self { dimensions, } }
+  /// Returns the number of files in each rank.
+  #[inline]
+  pub fn dimensions(&self,) -> &[usize] { &self.dimensions }
+  /// Returns the number of ranks.
+  #[inline]
+  pub fn ranks(&self,) -> usize { self.dimensions.len() }
+  /// Returns the total number of elements.
+  #[inline]
+  pub fn len(&self,) -> usize { self.dimensions.iter().sum() }
+  /// Returns `true` if the `Shape` describes no elements.
+  #[inline]
+  pub fn is_empty(&self,) -> bool { self.dimensions.iter().all(|&files,| files == 0,) }
+  /// Returns the offset of the first element of each rank followed by the total number of
+  /// elements, so rank `r` occupies `offsets[r]..offsets[r + 1]`.
+  pub fn offsets(&self,) -> Vec<usize> {
+    let mut offsets = Vec::with_capacity(self.ranks() + 1,);
+    offsets.push(0,);
+
+    let mut end = 0;
+    offsets.extend(self.dimensions.iter().map(|files,| { end += files; end },),);
+    offsets
+  }
+  /// Returns the `Shape` as its dimensions.
+  #[inline]
+  pub fn into_dimensions(self,) -> Vec<usize> { self.dimensions }
+}
+
+impl From<Vec<usize>> for Shape {
+  #[inline]
+  fn from(from: Vec<usize>,) -> Self { Self::new(from,) }
+}
+
+impl From<&[usize]> for Shape {
+  #[inline]
+  fn from(from: &[usize],) -> Self { Self::new(from.into(),) }
+}
+
+impl<const N: usize,> From<[usize; N]> for Shape {
+  #[inline]
+  fn from(from: [usize; N],) -> Self { Self::new(from.into(),) }
+}
+
+impl AsRef<[usize]> for Shape {
+  #[inline]
+  fn as_ref(&self,) -> &[usize] { &self.dimensions }
+}
+
+/// The error returned when a [`Shape`](crate::Shape) does not match the number of
+/// elements.
+#[derive(PartialEq, Eq, Clone, Copy, Debug,)]
+pub struct ShapeError {
+  /// The number of elements described by the `Shape`.
+  pub expected: usize,
+  /// The number of elements available.
+  pub found: usize,
+}
+
+impl fmt::Display for ShapeError {
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result {
+    write!(fmt, "wrong number of elements; expected `{}`, found `{}`", self.expected, self.found,)
+  }
+}
+
+#[cfg(feature = "std",)]
+impl std::error::Error for ShapeError {}
+
+impl<T, A,> Array<T, A,>
+  where A: Allocator, {
+  /// Returns the number of files (columns) in each rank (row).
+  #[inline]
+  pub fn dimensions(&self,) -> &[usize] { &self.dimensions }
+  /// Returns the [`Shape`](crate::Shape) of the `Array`.
+  #[inline]
+  pub fn shape(&self,) -> Shape { Shape::from(&*self.dimensions,) }
+  /// Returns `true` if `other` has the same rank lengths as the `Array`.
+  /// 
+  /// # Params
+  /// 
+  /// other --- The `Array` to compare against.  
+  #[inline]
+  pub fn same_shape<U, A2,>(&self, other: &Array<U, A2,>,) -> bool
+    where A2: Allocator, { *self.dimensions == *other.dimensions }
+  /// Reinterprets the elements of the `Array` under a new shape with the same number of
+  /// elements.
+  /// 
+  /// ```rust
+  /// use jagged_array::*;
+  /// 
+  /// let mut array = array![[1, 2], [3]];
+  /// array.reshape([1, 0, 2]).unwrap();
+  /// 
+  /// assert_eq!(array, array![[1], [], [2, 3]]);
+  /// ```
+  /// 
+  /// # Errors
+  /// 
+  /// Returns an error, leaving the `Array` unchanged, if `shape` does not describe
+  /// `Array.len` elements.
+  /// 
+  /// # Params
+  /// 
+  /// shape --- The new shape.  
+  pub fn reshape(&mut self, shape: impl Into<Shape>,) -> Result<(), ShapeError> {
+    let shape = shape.into();
+    let (expected, found,) = (shape.len(), self.len(),);
+    if expected != found { return Err(ShapeError { expected, found, }) }
+
+    self.dimensions.clear();
+    self.dimensions.extend_from_slice(shape.dimensions(),);
+    Ok(())
+  }
+}
+
+impl<T,> Array<T,> {
+  /// Creates a new `Array` with the given shape, calling `f` with the rank and file of each
+  /// element in order.
+  /// 
+  /// # Params
+  /// 
+  /// shape --- The shape of the `Array`.  
+  /// f --- Returns the element for a rank and file.  
+  pub fn from_shape_fn(shape: impl Into<Shape>, mut f: impl FnMut([usize; 2],) -> T,) -> Self {
+    let dimensions = shape.into().into_dimensions();
+    let mut elements = Vec::with_capacity(dimensions.iter().sum(),);
+    for (rank, &files,) in dimensions.iter().enumerate() {
+      elements.extend((0..files).map(|file,| f([rank, file],),),);
+    }
+
+    Self { dimensions, elements, }
+  }
+  /// Creates a new `Array` with the given shape from its elements in order.
+  /// 
+  /// # Errors
+  /// 
+  /// Returns an error if `shape` does not describe `elements.len()` elements.
+  /// 
+  /// # Params
+  /// 
+  /// shape --- The shape of the `Array`.  
+  /// elements --- The elements of the `Array`.  
+  pub fn from_shape_vec(shape: impl Into<Shape>, elements: Vec<T>,) -> Result<Self, ShapeError> {
+    let shape = shape.into();
+    let (expected, found,) = (shape.len(), elements.len(),);
+    if expected != found { return Err(ShapeError { expected, found, }) }
+
+    Ok(Self { dimensions: shape.into_dimensions(), elements, })
+  }
+}
+
+#[cfg(test,)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_shape() {
+    let mut array = Array::from_shape_fn([2, 0, 1], |[rank, file],| rank * 10 + file,);
+    assert_eq!(array, crate::array![[0, 1], [], [20]]);
+    assert_eq!(array.dimensions(), [2, 0, 1]);
+    assert_eq!(array.shape(), Shape::from([2, 0, 1]));
+    assert_eq!(array.shape().offsets(), [0, 2, 2, 3]);
+    assert!(array.same_shape(&crate::array![['a', 'b'], [], ['c']]));
+
+    assert_eq!(array.reshape([3]), Ok(()));
+    assert_eq!(array, crate::array![[0, 1, 20]]);
+    assert_eq!(array.reshape([1, 1]), Err(ShapeError { expected: 2, found: 3, }));
+
+    assert_eq!(Array::from_shape_vec([1, 2], alloc::vec![1, 2, 3]), Ok(crate::array![[1], [2, 3]]));
+    assert_eq!(Array::from_shape_vec([1], alloc::vec![1, 2]), Err(ShapeError { expected: 1, found: 2, }));
+  }
+}
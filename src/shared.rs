@@ -0,0 +1,234 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::{Array, Iter, Rank, Jagged, JaggedMut,};
+use alloc::{
+  vec::Vec,
+  sync::Arc,
+  alloc::{Allocator, Global,},
+};
+use core::{
+  fmt,
+  ops::{Deref, Index, IndexMut,},
+  borrow::Borrow,
+};
+
+/// A cheaply cloneable, reference counted [`Array`](crate::Array) which is copied on
+/// write.
+/// 
+/// Cloning an `ArcArray` only increments a reference count. Any mutation first ensures
+/// that this `ArcArray` is the only reference to the inner `Array`, cloning it if it is
+/// shared.
+/// 
+/// An `ArcArray` is `Send` and `Sync` whenever the `Array` it wraps is, which is whenever
+/// `T` and `A` are.
+/// 
+/// ```rust
+/// use jagged_array::*;
+/// 
+/// let shared = array![[2, 1]; 1, 2, 3].into_shared();
+/// let mut edited = shared.clone();
+/// edited[[1, 0]] = 4;
+/// 
+/// assert_eq!(shared[1], [3]);
+/// assert_eq!(edited[1], [4]);
+/// ```
+pub struct ArcArray<T, A: Allocator = Global,>(Arc<Array<T, A,>>,);
+
+impl<T, A,> ArcArray<T, A,>
+  where A: Allocator, {
+  /// Returns `true` if this `ArcArray` is the only reference to its `Array`.
+  #[inline]
+  pub fn is_unique(&mut self,) -> bool { Arc::get_mut(&mut self.0,).is_some() }
+  /// Returns `true` if both `ArcArray`s reference the same `Array`.
+  #[inline]
+  pub fn ptr_eq(&self, other: &Self,) -> bool { Arc::ptr_eq(&self.0, &other.0,) }
+}
+
+impl<T, A,> ArcArray<T, A,>
+  where T: Clone,
+    A: Allocator + Clone, {
+  /// Returns a mutable reference to the inner `Array`, cloning it first if it is shared.
+  #[inline]
+  pub fn make_mut(&mut self,) -> &mut Array<T, A,> { Arc::make_mut(&mut self.0,) }
+  /// Unwraps the inner `Array`, cloning it if it is shared.
+  pub fn into_array(self,) -> Array<T, A,> {
+    Arc::try_unwrap(self.0,).unwrap_or_else(|shared,| (*shared).clone(),)
+  }
+  /// A panic free way to index an `ArcArray`.
+  /// 
+  /// # Params
+  /// 
+  /// index --- The rank and file of the element.  
+  pub fn get_mut(&mut self, index: impl Borrow<[usize; 2]>,) -> Option<&mut T> { self.make_mut().get_mut(index,) }
+  /// A panic free way to index a rank of an `ArcArray`.
+  /// 
+  /// # Params
+  /// 
+  /// rank --- The rank to reference.  
+  pub fn rank_mut(&mut self, rank: usize,) -> Option<Rank<'_, T, A,>> { self.make_mut().rank_mut(rank,) }
+  /// Inserts a rank into the `ArcArray`.
+  /// 
+  /// # Panics
+  /// 
+  /// Panics if `rank` is outside the range `[0, ranks]`.
+  /// 
+  /// # Params
+  /// 
+  /// rank --- The index within the ranks to insert the values.  
+  /// elements --- The elements of the rank.  
+  pub fn insert(&mut self, rank: usize, elements: Vec<T, impl Allocator>,) { self.make_mut().insert(rank, elements,) }
+  /// Removes and returns a rank from the `ArcArray`.
+  /// 
+  /// # Panics
+  /// 
+  /// Panics if `rank` is ouside the range `[0, ranks)`.
+  /// 
+  /// # Params
+  /// 
+  /// rank --- The index of the rank to remove.  
+  pub fn remove(&mut self, rank: usize,) -> Vec<T> { self.make_mut().remove(rank,) }
+  /// Appends a rank to the `ArcArray`.
+  /// 
+  /// # Params
+  /// 
+  /// rank --- The elements to append.  
+  pub fn push(&mut self, rank: Vec<T, impl Allocator>,) { self.make_mut().push(rank,) }
+  /// Pops a rank from the `ArcArray`.
+  pub fn pop(&mut self,) -> Option<Vec<T>> { self.make_mut().pop() }
+}
+
+impl<T, A,> Array<T, A,>
+  where A: Allocator, {
+  /// Converts this `Array` into a cheaply cloneable [`ArcArray`](crate::ArcArray).
+  #[inline]
+  pub fn into_shared(self,) -> ArcArray<T, A,> { ArcArray(Arc::new(self,),) }
+}
+
+impl<T, A,> From<Array<T, A,>> for ArcArray<T, A,>
+  where A: Allocator, {
+  #[inline]
+  fn from(from: Array<T, A,>,) -> Self { from.into_shared() }
+}
+
+impl<T, A,> Clone for ArcArray<T, A,>
+  where A: Allocator, {
+  #[inline]
+  fn clone(&self,) -> Self { ArcArray(self.0.clone(),) }
+}
+
+impl<T, A,> Default for ArcArray<T, A,>
+  where A: Allocator + Clone + Default, {
+  #[inline]
+  fn default() -> Self { Array::default().into_shared() }
+}
+
+impl<T, A,> Deref for ArcArray<T, A,>
+  where A: Allocator, {
+  type Target = Array<T, A,>;
+
+  #[inline]
+  fn deref(&self,) -> &Self::Target { &self.0 }
+}
+
+impl<T, A,> AsRef<Array<T, A,>> for ArcArray<T, A,>
+  where A: Allocator, {
+  #[inline]
+  fn as_ref(&self,) -> &Array<T, A,> { &self.0 }
+}
+
+impl<T, U, A,> PartialEq<ArcArray<U, A,>> for ArcArray<T, A,>
+  where T: PartialEq<U>,
+    A: Allocator, {
+  fn eq(&self, rhs: &ArcArray<U, A,>,) -> bool { *self.0 == *rhs.0 }
+}
+
+impl<T, A,> Eq for ArcArray<T, A,>
+  where T: Eq,
+    A: Allocator, {}
+
+impl<T, A,> Index<usize> for ArcArray<T, A,>
+  where A: Allocator, {
+  type Output = [T];
+
+  #[inline]
+  fn index(&self, index: usize,) -> &Self::Output { &self.0[index] }
+}
+
+impl<T, A,> IndexMut<usize> for ArcArray<T, A,>
+  where T: Clone,
+    A: Allocator + Clone, {
+  #[inline]
+  fn index_mut(&mut self, index: usize,) -> &mut Self::Output { &mut self.make_mut()[index] }
+}
+
+impl<T, A,> Index<[usize; 2]> for ArcArray<T, A,>
+  where A: Allocator, {
+  type Output = T;
+
+  #[inline]
+  fn index(&self, index: [usize; 2],) -> &Self::Output { &self.0[index] }
+}
+
+impl<T, A,> IndexMut<[usize; 2]> for ArcArray<T, A,>
+  where T: Clone,
+    A: Allocator + Clone, {
+  #[inline]
+  fn index_mut(&mut self, index: [usize; 2],) -> &mut Self::Output { &mut self.make_mut()[index] }
+}
+
+impl<T, A,> Jagged for ArcArray<T, A,>
+  where A: Allocator, {
+  type Element = T;
+  type RankIter<'a,> = Iter<'a, T,>
+    where Self: 'a;
+
+  #[inline]
+  fn ranks(&self,) -> usize { self.0.ranks() }
+  #[inline]
+  fn rank(&self, rank: usize,) -> Option<&[T]> { self.0.rank(rank,) }
+  #[inline]
+  fn files(&self, rank: usize,) -> usize { self.0.files(rank,) }
+  #[inline]
+  fn len(&self,) -> usize { self.0.len() }
+  #[inline]
+  fn rank_iter(&self,) -> Iter<'_, T,> { self.0.iter() }
+}
+
+impl<T, A,> JaggedMut for ArcArray<T, A,>
+  where T: Clone,
+    A: Allocator + Clone, {
+  #[inline]
+  fn rank_mut(&mut self, rank: usize,) -> Option<&mut [T]> { self.make_mut().rank_mut(rank,).map(Into::into,) }
+}
+
+impl<T, A,> fmt::Debug for ArcArray<T, A,>
+  where T: fmt::Debug,
+    A: Allocator, {
+  #[inline]
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result { fmt::Debug::fmt(&*self.0, fmt,) }
+}
+
+#[cfg(test,)]
+mod tests {
+  #[test]
+  fn test_arc_array() {
+    fn assert_send_sync<T: Send + Sync,>(_: &T,) {}
+
+    let mut shared = crate::array![[2, 1]; 1, 2, 3].into_shared();
+    assert_send_sync(&shared);
+    assert!(shared.is_unique());
+
+    let mut clone = shared.clone();
+    assert!(clone.ptr_eq(&shared));
+    assert!(!clone.is_unique());
+
+    clone.rank_mut(0).expect("failed to get the rank").push(4);
+    assert!(!clone.ptr_eq(&shared));
+    assert!(shared.is_unique());
+    shared.push(alloc::vec![5]);
+
+    assert_eq!(shared.into_array(), crate::array![[2, 1, 1]; 1, 2, 3, 5]);
+    assert_eq!(clone.into_array(), crate::array![[3, 1]; 1, 2, 4, 3]);
+  }
+}
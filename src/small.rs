@@ -0,0 +1,338 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::{Array, Iter, IterMut, Jagged, JaggedMut, JaggedPush, CapacityError, inline_vec::InlineVec,};
+use alloc::{
+  vec::Vec,
+  alloc::Allocator,
+};
+use core::{
+  fmt,
+  ops::{Index, IndexMut,},
+  borrow::Borrow,
+};
+
+/// The storage of a [`SmallArray`](crate::SmallArray).
+#[derive(Clone,)]
+enum Storage<T, const RANKS: usize, const ELEMS: usize,> {
+  /// The dimensions and elements are stored inline.
+  Inline {
+    /// The dimensions of the `SmallArray`.
+    dimensions: InlineVec<usize, RANKS>,
+    /// The elements of the `SmallArray`.
+    elements: InlineVec<T, ELEMS>,
+  },
+  /// The dimensions and elements have spilled onto the heap.
+  Heap(Array<T,>,),
+}
+
+/// A jagged array which stores up to `RANKS` ranks and `ELEMS` elements inline before
+/// spilling onto the heap.
+/// 
+/// A `SmallArray` can be indexed either by a `rank/file` pair or simply by a `rank`.
+/// 
+/// ```rust
+/// use jagged_array::*;
+/// 
+/// let mut array = SmallArray::<_, 2, 4>::new();
+/// array.push(vec![1, 2]);
+/// array.push(vec![3]);
+/// assert!(array.is_inline());
+/// assert_eq!(array[[0, 1]], 2);
+/// 
+/// array.push(vec![4, 5]);
+/// assert!(!array.is_inline());
+/// assert_eq!(array.into_array(), array![[2, 1, 2]; 1, 2, 3, 4, 5]);
+/// ```
+#[derive(Clone,)]
+pub struct SmallArray<T, const RANKS: usize, const ELEMS: usize,>(Storage<T, RANKS, ELEMS,>,);
+
+impl<T, const RANKS: usize, const ELEMS: usize,> SmallArray<T, RANKS, ELEMS,> {
+  /// Creates a new empty `SmallArray` with no dimensions or elements.
+  #[inline]
+  pub fn new() -> Self {
+    SmallArray(Storage::Inline { dimensions: InlineVec::new(), elements: InlineVec::new(), },)
+  }
+  /// Returns `true` if the `SmallArray` has not spilled onto the heap.
+  #[inline]
+  pub fn is_inline(&self,) -> bool { matches!(self.0, Storage::Inline { .. },) }
+  /// Returns the dimensions and elements of the `SmallArray`.
+  #[inline]
+  fn parts(&self,) -> (&[usize], &[T],) {
+    match &self.0 {
+      Storage::Inline { dimensions, elements, } => (dimensions, elements,),
+      Storage::Heap(array,) => (&array.dimensions, &array.elements,),
+    }
+  }
+  /// Returns the dimensions and a mutable reference to the elements of the `SmallArray`.
+  #[inline]
+  fn parts_mut(&mut self,) -> (&[usize], &mut [T],) {
+    match &mut self.0 {
+      Storage::Inline { dimensions, elements, } => (dimensions, elements,),
+      Storage::Heap(array,) => (&array.dimensions, &mut array.elements,),
+    }
+  }
+  /// Returns the number of elements in the `SmallArray`.
+  #[inline]
+  pub fn len(&self,) -> usize { self.parts().1.len() }
+  /// Returns `true` if the `SmallArray` contains no elements.
+  #[inline]
+  pub fn is_empty(&self,) -> bool { self.len() == 0 }
+  /// Returns the number of ranks (rows) making up the `SmallArray`.
+  #[inline]
+  pub fn ranks(&self,) -> usize { self.parts().0.len() }
+  /// Returns the number of files (columns) within the given `rank`.
+  /// 
+  /// Defaults to `0` for nonexistant ranks.
+  /// 
+  /// # Params
+  /// 
+  /// rank --- The rank to get the files for.  
+  pub fn files(&self, rank: usize,) -> usize { self.parts().0.get(rank,).copied().unwrap_or(0,) }
+  /// A panic free way to index a rank of a `SmallArray`.
+  /// 
+  /// # Params
+  /// 
+  /// rank --- The rank to reference.  
+  pub fn rank(&self, rank: usize,) -> Option<&[T]> {
+    let (dimensions, elements,) = self.parts();
+    let files = *dimensions.get(rank,)?;
+    let start = dimensions[..rank].iter().sum::<usize>();
+
+    Some(&elements[start..start + files])
+  }
+  /// A panic free way to mutably index a rank of a `SmallArray`.
+  /// 
+  /// # Params
+  /// 
+  /// rank --- The rank to reference.  
+  pub fn rank_mut(&mut self, rank: usize,) -> Option<&mut [T]> {
+    let (dimensions, elements,) = self.parts_mut();
+    let files = *dimensions.get(rank,)?;
+    let start = dimensions[..rank].iter().sum::<usize>();
+
+    Some(&mut elements[start..start + files])
+  }
+  /// A panic free way to index a `SmallArray`.
+  /// 
+  /// # Params
+  /// 
+  /// index --- The rank and file of the element.  
+  pub fn get(&self, index: impl Borrow<[usize; 2]>,) -> Option<&T> {
+    let index = index.borrow();
+
+    self.rank(index[0],)?.get(index[1],)
+  }
+  /// A panic free way to index a `SmallArray`.
+  /// 
+  /// # Params
+  /// 
+  /// index --- The rank and file of the element.  
+  pub fn get_mut(&mut self, index: impl Borrow<[usize; 2]>,) -> Option<&mut T> {
+    let index = index.borrow();
+
+    self.rank_mut(index[0],)?.get_mut(index[1],)
+  }
+  /// Moves the inline dimensions and elements onto the heap.
+  fn spill(&mut self,) -> &mut Array<T,> {
+    if let Storage::Inline { dimensions, elements, } = &mut self.0 {
+      let mut array = Array::with_capacity(2 * ELEMS,);
+      array.dimensions.extend(dimensions.drain(),);
+      array.elements.extend(elements.drain(),);
+
+      self.0 = Storage::Heap(array,);
+    }
+
+    match &mut self.0 {
+      Storage::Heap(array,) => array,
+      Storage::Inline { .. } => unreachable!(),
+    }
+  }
+  /// Appends a rank to the `SmallArray`, spilling onto the heap if there is not enough
+  /// inline space.
+  /// 
+  /// # Params
+  /// 
+  /// rank --- The elements to append.  
+  pub fn push(&mut self, rank: Vec<T, impl Allocator>,) {
+    if let Storage::Inline { dimensions, elements, } = &mut self.0 {
+      if dimensions.len() < RANKS && elements.len() + rank.len() <= ELEMS {
+        let _ = dimensions.try_push(rank.len(),);
+        for x in rank {
+          //Cannot fail because we just checked there is enough space.
+          let _ = elements.try_push(x,);
+        }
+
+        return
+      }
+    }
+
+    self.spill().push(rank,)
+  }
+  /// Pops a rank from the `SmallArray`.
+  pub fn pop(&mut self,) -> Option<Vec<T>> {
+    match &mut self.0 {
+      Storage::Inline { dimensions, elements, } => {
+        let files = dimensions.pop()?;
+        let start = elements.len() - files;
+
+        Some(elements.drain_from(start,).collect())
+      },
+      Storage::Heap(array,) => array.pop(),
+    }
+  }
+  /// Returns an iterator over all of the ranks (rows) of this `SmallArray`.
+  pub fn iter<'a,>(&'a self,) -> Iter<'a, T,> {
+    let (dimensions, elements,) = self.parts();
+
+    Iter::new(dimensions, elements,)
+  }
+  /// Returns a mutable iterator over all of the ranks (rows) of this `SmallArray`.
+  pub fn iter_mut<'a,>(&'a mut self,) -> IterMut<'a, T,> {
+    let (dimensions, elements,) = self.parts_mut();
+
+    IterMut::new(dimensions, elements,)
+  }
+  /// Converts this `SmallArray` into an [`Array`](crate::Array).
+  pub fn into_array(mut self,) -> Array<T,> {
+    core::mem::replace(self.spill(), Array::new(),)
+  }
+}
+
+impl<T, const RANKS: usize, const ELEMS: usize,> Default for SmallArray<T, RANKS, ELEMS,> {
+  #[inline]
+  fn default() -> Self { Self::new() }
+}
+
+impl<T, const RANKS: usize, const ELEMS: usize,> From<Array<T,>> for SmallArray<T, RANKS, ELEMS,> {
+  #[inline]
+  fn from(from: Array<T,>,) -> Self { SmallArray(Storage::Heap(from,),) }
+}
+
+impl<T, U, const RANKS: usize, const ELEMS: usize, const R: usize, const E: usize,> PartialEq<SmallArray<U, R, E,>> for SmallArray<T, RANKS, ELEMS,>
+  where T: PartialEq<U>, {
+  fn eq(&self, rhs: &SmallArray<U, R, E,>,) -> bool { self.parts().0 == rhs.parts().0 && self.parts().1 == rhs.parts().1 }
+}
+
+impl<T, const RANKS: usize, const ELEMS: usize,> Eq for SmallArray<T, RANKS, ELEMS,>
+  where T: Eq, {}
+
+impl<T, const RANKS: usize, const ELEMS: usize,> Index<usize> for SmallArray<T, RANKS, ELEMS,> {
+  type Output = [T];
+
+  fn index(&self, index: usize,) -> &Self::Output {
+    self.rank(index,).expect("`index` is not within the `SmallArray` bounds")
+  }
+}
+
+impl<T, const RANKS: usize, const ELEMS: usize,> IndexMut<usize> for SmallArray<T, RANKS, ELEMS,> {
+  fn index_mut(&mut self, index: usize,) -> &mut Self::Output {
+    self.rank_mut(index,).expect("`index` is not within the `SmallArray` bounds")
+  }
+}
+
+impl<T, const RANKS: usize, const ELEMS: usize,> Index<[usize; 2]> for SmallArray<T, RANKS, ELEMS,> {
+  type Output = T;
+
+  fn index(&self, index: [usize; 2],) -> &Self::Output {
+    self.get(index,).expect("`index` is not within the `SmallArray` bounds")
+  }
+}
+
+impl<T, const RANKS: usize, const ELEMS: usize,> IndexMut<[usize; 2]> for SmallArray<T, RANKS, ELEMS,> {
+  fn index_mut(&mut self, index: [usize; 2],) -> &mut Self::Output {
+    self.get_mut(index,).expect("`index` is not within the `SmallArray` bounds")
+  }
+}
+
+impl<T, const RANKS: usize, const ELEMS: usize,> Jagged for SmallArray<T, RANKS, ELEMS,> {
+  type Element = T;
+  type RankIter<'a,> = Iter<'a, T,>
+    where Self: 'a;
+
+  #[inline]
+  fn ranks(&self,) -> usize { SmallArray::ranks(self,) }
+  #[inline]
+  fn rank(&self, rank: usize,) -> Option<&[T]> { SmallArray::rank(self, rank,) }
+  #[inline]
+  fn files(&self, rank: usize,) -> usize { SmallArray::files(self, rank,) }
+  #[inline]
+  fn len(&self,) -> usize { SmallArray::len(self,) }
+  #[inline]
+  fn rank_iter(&self,) -> Iter<'_, T,> { SmallArray::iter(self,) }
+}
+
+impl<T, const RANKS: usize, const ELEMS: usize,> JaggedMut for SmallArray<T, RANKS, ELEMS,> {
+  #[inline]
+  fn rank_mut(&mut self, rank: usize,) -> Option<&mut [T]> { SmallArray::rank_mut(self, rank,) }
+}
+
+impl<T, const RANKS: usize, const ELEMS: usize,> JaggedPush for SmallArray<T, RANKS, ELEMS,> {
+  fn push<I,>(&mut self, rank: I,) -> Result<(), CapacityError<I::IntoIter>>
+    where I: IntoIterator<Item = T>,
+      I::IntoIter: ExactSizeIterator, {
+    let rank = rank.into_iter();
+    if let Storage::Inline { dimensions, elements, } = &mut self.0 {
+      if dimensions.len() < RANKS && ELEMS - elements.len() >= rank.len() {
+        let len = elements.len();
+        //Cannot fail because we just checked there is enough space.
+        let _ = elements.try_splice(len, rank,);
+        let _ = dimensions.try_push(elements.len() - len,);
+
+        return Ok(())
+      }
+    }
+
+    JaggedPush::push(self.spill(), rank,)
+  }
+}
+
+impl<'a, T, const RANKS: usize, const ELEMS: usize,> IntoIterator for &'a SmallArray<T, RANKS, ELEMS,> {
+  type IntoIter = Iter<'a, T,>;
+  type Item = &'a [T];
+
+  #[inline]
+  fn into_iter(self,) -> Self::IntoIter { self.iter() }
+}
+
+impl<'a, T, const RANKS: usize, const ELEMS: usize,> IntoIterator for &'a mut SmallArray<T, RANKS, ELEMS,> {
+  type IntoIter = IterMut<'a, T,>;
+  type Item = &'a mut [T];
+
+  #[inline]
+  fn into_iter(self,) -> Self::IntoIter { self.iter_mut() }
+}
+
+impl<T, const RANKS: usize, const ELEMS: usize,> fmt::Debug for SmallArray<T, RANKS, ELEMS,>
+  where T: fmt::Debug, {
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result {
+    fmt.debug_list()
+    .entries(self.iter(),)
+    .finish()
+  }
+}
+
+#[cfg(test,)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_small_array() {
+    let mut array = SmallArray::<_, 2, 3>::new();
+    array.push(alloc::vec![1, 2]);
+    array.push(alloc::vec![3]);
+    assert!(array.is_inline());
+    assert_eq!(array.len(), 3);
+    assert_eq!(array.ranks(), 2);
+    assert_eq!(array.files(0), 2);
+    assert_eq!(array.rank(1), Some(&[3][..]));
+    assert_eq!(array.get([0, 1]), Some(&2));
+
+    array[[1, 0]] = 4;
+    assert_eq!(array.pop(), Some(alloc::vec![4]));
+    array.push(alloc::vec![5, 6]);
+    assert!(!array.is_inline());
+    assert_eq!(array.iter().collect::<Vec<_>>(), [&[1, 2][..], &[5, 6][..]]);
+    assert_eq!(array.into_array(), crate::array![[2, 2]; 1, 2, 5, 6]);
+  }
+}
@@ -0,0 +1,109 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::{Array, array::rank_starts,};
+use alloc::{
+  vec::Vec,
+  alloc::Allocator,
+};
+use core::cmp::Ordering;
+
+impl<T, A,> Array<T, A,>
+  where A: Allocator, {
+  /// Sorts the elements of each rank, preserving the order of equal elements.
+  pub fn sort_each_rank(&mut self,)
+    where T: Ord, {
+    for rank in self.iter_mut() { rank.sort() }
+  }
+  /// Sorts the elements of each rank by the key returned by `key`, preserving the order of
+  /// elements with equal keys.
+  /// 
+  /// # Params
+  /// 
+  /// key --- Returns the key to sort an element by.  
+  pub fn sort_each_rank_by_key<K,>(&mut self, mut key: impl FnMut(&T,) -> K,)
+    where K: Ord, {
+    for rank in self.iter_mut() { rank.sort_by_key(&mut key,) }
+  }
+  /// Sorts the elements of each rank without preserving the order of equal elements.
+  pub fn sort_each_rank_unstable(&mut self,)
+    where T: Ord, {
+    for rank in self.iter_mut() { rank.sort_unstable() }
+  }
+  /// Removes consecutive repeated elements within each rank.
+  /// 
+  /// Elements are never compared across ranks so each rank keeps at least one element if
+  /// it had any.
+  /// 
+  /// # Complexity
+  /// 
+  /// O(N) where `N is Array.len`; `elements` is compacted in a single pass.
+  pub fn dedup_each_rank(&mut self,)
+    where T: PartialEq, {
+    let (mut read, mut write,) = (0, 0,);
+
+    for files in self.dimensions.iter_mut() {
+      let (start, end,) = (write, read + *files,);
+      while read < end {
+        //The elements in `[write, read)` are duplicates so they can be swapped past.
+        if write == start || self.elements[read] != self.elements[write - 1] {
+          self.elements.swap(read, write,);
+          write += 1;
+        }
+        read += 1;
+      }
+      *files = write - start;
+    }
+    self.elements.truncate(write,);
+  }
+  /// Sorts the ranks themselves using `compare`, preserving the order of equal ranks.
+  /// 
+  /// ```rust
+  /// use jagged_array::*;
+  /// 
+  /// let mut array = array![[3, 1, 5], [2], [4, 1]];
+  /// array.sort_ranks_by(|a, b| a.len().cmp(&b.len()));
+  /// 
+  /// assert_eq!(array, array![[2], [4, 1], [3, 1, 5]]);
+  /// ```
+  /// 
+  /// # Complexity
+  /// 
+  /// O(R log R) comparisons and O(N) moves where `R is Array.ranks` and `N is Array.len`.
+  /// 
+  /// # Params
+  /// 
+  /// compare --- Compares two ranks.  
+  pub fn sort_ranks_by(&mut self, mut compare: impl FnMut(&[T], &[T],) -> Ordering,) {
+    let starts = rank_starts(&self.dimensions,);
+    let rank = |rank: usize,| &self.elements[starts[rank]..starts[rank] + self.dimensions[rank]];
+    let mut order = (0..self.ranks()).collect::<Vec<_>>();
+    order.sort_by(|&a, &b,| compare(rank(a,), rank(b,),),);
+
+    //Safe because `order` is a permutation of the ranks.
+    unsafe { self.reorder_ranks(&order,) }
+  }
+}
+
+#[cfg(test,)]
+mod tests {
+  #[test]
+  fn test_sort_array() {
+    let mut array = crate::array![[3, 1, 1, 3], [], [2, 2, 2], [5, 4]];
+    array.sort_each_rank();
+    assert_eq!(array, crate::array![[1, 1, 3, 3], [], [2, 2, 2], [4, 5]]);
+
+    array.dedup_each_rank();
+    assert_eq!(array, crate::array![[1, 3], [], [2], [4, 5]]);
+
+    array.sort_each_rank_by_key(|x,| -x,);
+    assert_eq!(array, crate::array![[3, 1], [], [2], [5, 4]]);
+
+    array.sort_ranks_by(|a, b,| a.len().cmp(&b.len(),),);
+    assert_eq!(array, crate::array![[], [2], [3, 1], [5, 4]]);
+
+    array.sort_ranks_by(|a, b,| b.cmp(a,),);
+    array.sort_each_rank_unstable();
+    assert_eq!(array, crate::array![[4, 5], [1, 3], [2], []]);
+  }
+}
@@ -0,0 +1,145 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::{Array, Iter,};
+use alloc::{
+  vec::Vec,
+  alloc::Allocator,
+};
+use core::{
+  fmt,
+  iter::FusedIterator,
+};
+
+/// Returns the dimensions of the transpose of `dimensions` and the index each element is
+/// moved to.
+/// 
+/// # Complexity
+/// 
+/// O(N + R + F) where `N` is the number of elements, `R` the number of ranks and `F` the
+/// length of the longest rank.
+/// 
+/// # Params
+/// 
+/// dimensions --- The rank dimensions.  
+fn transposed(dimensions: &[usize],) -> (Vec<usize>, Vec<usize>,) {
+  let files = dimensions.iter().copied().max().unwrap_or(0,);
+  //Count the ranks which end at each file and then accumulate from the end.
+  let mut transposed = alloc::vec![0; files];
+  for &files in dimensions { if let Some(end,) = files.checked_sub(1,) { transposed[end] += 1 } }
+  for file in (1..files).rev() { transposed[file - 1] += transposed[file] }
+
+  let mut next = Vec::with_capacity(files,);
+  let mut start = 0;
+  for &files in &transposed { next.push(start,); start += files }
+
+  let mut destinations = Vec::with_capacity(start,);
+  for &files in dimensions {
+    for next in &mut next[..files] { destinations.push(*next,); *next += 1 }
+  }
+
+  (transposed, destinations,)
+}
+
+/// An iterator over a single file (column) of an [`Array`](crate::Array).
+/// 
+/// Yields the element at the file for each rank or `None` for ranks which are too short.
+pub struct Column<'a, T,> {
+  /// The file being iterated.
+  file: usize,
+  /// The remaining ranks.
+  ranks: Iter<'a, T,>,
+}
+
+impl<'a, T,> Iterator for Column<'a, T,> {
+  type Item = Option<&'a T>;
+
+  #[inline]
+  fn size_hint(&self,) -> (usize, Option<usize>,) { self.ranks.size_hint() }
+  #[inline]
+  fn next(&mut self,) -> Option<Self::Item> { self.ranks.next().map(|rank,| rank.get(self.file,),) }
+}
+
+impl<T,> FusedIterator for Column<'_, T,> {}
+
+impl<T,> Clone for Column<'_, T,> {
+  #[inline]
+  fn clone(&self,) -> Self { Column { file: self.file, ranks: self.ranks.clone(), } }
+}
+
+impl<T,> fmt::Debug for Column<'_, T,>
+  where T: fmt::Debug, {
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result { fmt.debug_list().entries(self.clone(),).finish() }
+}
+
+impl<T, A,> Array<T, A,>
+  where A: Allocator, {
+  /// Transposes the `Array` so that rank `f` of the result holds file `f` of every rank
+  /// which is long enough, in rank order.
+  /// 
+  /// ```rust
+  /// use jagged_array::*;
+  /// 
+  /// let array = array![[1, 2, 3], [4], [], [5, 6]];
+  /// assert_eq!(array.transpose(), array![[1, 4, 5], [2, 6], [3]]);
+  /// ```
+  /// 
+  /// # Complexity
+  /// 
+  /// O(N + R + F) where `N is Array.len`, `R is Array.ranks` and `F` is the length of the
+  /// longest rank. The elements are moved in place.
+  pub fn transpose(mut self,) -> Self {
+    let (dimensions, mut destinations,) = transposed(&self.dimensions,);
+    //Swap each element directly into place; every swap settles at least one element.
+    for index in 0..destinations.len() {
+      while destinations[index] != index {
+        let destination = destinations[index];
+        self.elements.swap(index, destination,);
+        destinations.swap(index, destination,);
+      }
+    }
+
+    self.dimensions.clear();
+    self.dimensions.extend_from_slice(&dimensions,);
+    self
+  }
+  /// Transposes references to the elements of the `Array` so that rank `f` of the result
+  /// holds file `f` of every rank which is long enough, in rank order.
+  /// 
+  /// # Complexity
+  /// 
+  /// O(N + R + F) where `N is Array.len`, `R is Array.ranks` and `F` is the length of the
+  /// longest rank.
+  pub fn transpose_ref(&self,) -> Array<&T,> {
+    let (dimensions, destinations,) = transposed(&self.dimensions,);
+    let mut sources = alloc::vec![0; destinations.len()];
+    for (source, &destination,) in destinations.iter().enumerate() { sources[destination] = source }
+
+    Array { dimensions, elements: sources.into_iter().map(|source,| &self.elements[source],).collect(), }
+  }
+  /// Returns an iterator over a single file (column) of the `Array`, yielding the element
+  /// at `file` for each rank or `None` for ranks which are too short.
+  /// 
+  /// # Params
+  /// 
+  /// file --- The file to iterate over.  
+  #[inline]
+  pub fn column(&self, file: usize,) -> Column<'_, T,> { Column { file, ranks: self.iter(), } }
+}
+
+#[cfg(test,)]
+mod tests {
+  use alloc::vec::Vec;
+
+  #[test]
+  fn test_transpose() {
+    let array = crate::array![[1, 2], [], [3, 4, 5], [6]];
+    assert_eq!(array.column(1).collect::<Vec<_>>(), [Some(&2), None, Some(&4), None]);
+    assert_eq!(array.transpose_ref(), crate::array![[&1, &3, &6], [&2, &4], [&5]]);
+
+    let transposed = array.transpose();
+    assert_eq!(transposed, crate::array![[1, 3, 6], [2, 4], [5]]);
+    assert_eq!(transposed.transpose(), crate::array![[1, 2, 5], [3, 4], [6]]);
+    assert_eq!(crate::Array::<i32>::new().transpose().ranks(), 0);
+  }
+}